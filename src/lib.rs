@@ -1,5 +1,11 @@
 #[cfg(feature = "math")]
 pub mod math;
+#[cfg(feature = "packing")]
+pub mod packing;
+#[cfg(feature = "spatial")]
+pub mod spatial;
+#[cfg(feature = "ui")]
+pub mod ui;
 
 #[cfg(feature = "color")]
 pub use palette;
@@ -1,21 +1,23 @@
-use crate::math::{Decimal, Integer, Vector, Vector2};
+use crate::math::{Decimal, Integer, Unit, Vector, Vector2};
 
 #[derive(Debug, Clone, Copy, Default)]
-pub struct CubicBezier<D>
+pub struct CubicBezier<D, U = ()>
 where
     D: Decimal,
+    U: Unit,
 {
-    pub p0: Vector2<D, ()>,
-    pub p1: Vector2<D, ()>,
-    pub p2: Vector2<D, ()>,
-    pub p3: Vector2<D, ()>,
+    pub p0: Vector2<D, U>,
+    pub p1: Vector2<D, U>,
+    pub p2: Vector2<D, U>,
+    pub p3: Vector2<D, U>,
 }
 
-impl<D> CubicBezier<D>
+impl<D, U> CubicBezier<D, U>
 where
     D: Decimal,
+    U: Unit,
 {
-    pub fn point_at(&self, t: D) -> Vector2<D, ()> {
+    pub fn point_at(&self, t: D) -> Vector2<D, U> {
         // TODO: Computed 3 might not be the exact same as literal 3, account for that?
         let three = D::one() + D::one() + D::one();
         let u = D::one() - t;
@@ -35,7 +37,7 @@ where
                 + ttt * self.p3.y,
         )
     }
-    pub fn derivative(&self, t: D) -> Vector2<D, ()> {
+    pub fn derivative(&self, t: D) -> Vector2<D, U> {
         // TODO: Computed 3 might not be the exact same as 3, account for that?
         let three = D::one() + D::one() + D::one();
         let six = three * (D::one() + D::one());
@@ -0,0 +1,247 @@
+use crate::math::{
+    Affine2, Decimal, Orthographic, Radians, Rect2, ScreenSpace, Vector2, ViewPipeline, ViewSpace,
+    Viewport, WorldSpace,
+};
+
+/// Stateful 2D camera looking at `position`, one view unit is one screen unit
+/// at a zoom of one.
+///
+/// `update` must be called once per frame to follow the target, stay inside
+/// the bounds and animate the shake.
+#[derive(Debug, Clone, Copy)]
+pub struct Camera2<D>
+where
+    D: Decimal,
+{
+    pub position: Vector2<D, WorldSpace>,
+    pub rotation: Radians<D>,
+    pub viewport: Viewport<D>,
+    /// Point the camera moves towards on every update.
+    pub target: Option<Vector2<D, WorldSpace>>,
+    /// Half extents of the area around the camera position the target can
+    /// move in without the camera following it.
+    pub dead_zone: Vector2<D, WorldSpace>,
+    /// Time in seconds the camera takes to cover about two thirds of the
+    /// distance to the target, zero snaps to it.
+    pub follow_lag: D,
+    /// Area the visible rect is kept inside, centered on it when too small.
+    pub bounds: Option<Rect2<D, WorldSpace>>,
+    /// Offset reached by the shake at full trauma.
+    pub max_shake_offset: Vector2<D, WorldSpace>,
+    /// Rotation reached by the shake at full trauma.
+    pub max_shake_angle: Radians<D>,
+    /// Oscillations per second of the shake.
+    pub shake_frequency: D,
+    /// Trauma lost per second.
+    pub trauma_decay: D,
+    zoom: D,
+    min_zoom: D,
+    max_zoom: D,
+    trauma: D,
+    shake_time: D,
+}
+impl<D> Camera2<D>
+where
+    D: Decimal,
+{
+    pub fn new(position: Vector2<D, WorldSpace>, viewport: Viewport<D>) -> Self {
+        Self {
+            position,
+            rotation: Radians(D::zero()),
+            viewport,
+            target: None,
+            dead_zone: Vector2::new(D::zero(), D::zero()),
+            follow_lag: D::zero(),
+            bounds: None,
+            max_shake_offset: Vector2::new(D::zero(), D::zero()),
+            max_shake_angle: Radians(D::zero()),
+            shake_frequency: D::from_i32(15),
+            trauma_decay: D::one(),
+            zoom: D::one(),
+            min_zoom: smallest_zoom(),
+            max_zoom: D::max_value(),
+            trauma: D::zero(),
+            shake_time: D::zero(),
+        }
+    }
+
+    /// Magnification, two shows the world twice as big.
+    pub fn zoom(&self) -> D {
+        self.zoom
+    }
+    pub fn set_zoom(&mut self, zoom: D) {
+        self.zoom = zoom.clamp(self.min_zoom, self.max_zoom);
+    }
+    /// Zoom limits, the current zoom is clamped to them. The minimum is kept
+    /// strictly positive so the view stays invertible.
+    pub fn set_zoom_limits(&mut self, min: D, max: D) {
+        self.min_zoom = min.max(smallest_zoom());
+        self.max_zoom = max.max(self.min_zoom);
+        self.set_zoom(self.zoom);
+    }
+    pub fn zoom_limits(&self) -> (D, D) {
+        (self.min_zoom, self.max_zoom)
+    }
+    /// Changes the zoom while keeping the world point under `point` in place.
+    pub fn zoom_at(&mut self, zoom: D, point: Vector2<D, ScreenSpace>) {
+        let Some(before) = self.pipeline().unproject(point) else {
+            self.set_zoom(zoom);
+            return;
+        };
+        self.set_zoom(zoom);
+        if let Some(after) = self.pipeline().unproject(point) {
+            self.position += before - after;
+        }
+    }
+
+    pub fn trauma(&self) -> D {
+        self.trauma
+    }
+    /// Adds trauma, clamped to one. The shake grows with its square.
+    pub fn add_trauma(&mut self, amount: D) {
+        self.trauma = (self.trauma + amount).clamp(D::zero(), D::one());
+    }
+
+    /// Advances the camera by `dt` seconds.
+    pub fn update(&mut self, dt: D) {
+        if let Some(target) = self.target {
+            let goal = Vector2::new(
+                dead_zone_goal(self.position.x, target.x, self.dead_zone.x),
+                dead_zone_goal(self.position.y, target.y, self.dead_zone.y),
+            );
+            let alpha = if self.follow_lag > D::zero() {
+                D::one() - (-dt / self.follow_lag).exp()
+            } else {
+                D::one()
+            };
+            self.position += (goal - self.position) * alpha;
+        }
+        self.clamp_to_bounds();
+
+        self.trauma = (self.trauma - self.trauma_decay * dt).max(D::zero());
+        if self.trauma > D::zero() {
+            self.shake_time += dt * self.shake_frequency;
+        } else {
+            self.shake_time = D::zero();
+        }
+    }
+    /// Moves the camera so the visible rect stays inside the bounds.
+    pub fn clamp_to_bounds(&mut self) {
+        let Some(bounds) = self.bounds else {
+            return;
+        };
+        let two = D::one() + D::one();
+        let half = self.visible_extents() / two;
+        let axis = |position: D, half: D, min: D, max: D| {
+            if max - min < half * two {
+                (min + max) / two
+            } else {
+                position.clamp(min + half, max - half)
+            }
+        };
+        let (min, max) = (bounds.min(), bounds.max());
+        self.position = Vector2::new(
+            axis(self.position.x, half.x, min.x, max.x),
+            axis(self.position.y, half.y, min.y, max.y),
+        );
+    }
+
+    /// World to view transform, including the shake.
+    pub fn view(&self) -> Affine2<D, WorldSpace, ViewSpace> {
+        let shake = self.trauma * self.trauma;
+        let offset = Vector2::new(
+            self.max_shake_offset.x * shake * noise(0, self.shake_time),
+            self.max_shake_offset.y * shake * noise(1, self.shake_time),
+        );
+        let angle = self.max_shake_angle.0 * shake * noise(2, self.shake_time);
+        Affine2::from_camera(
+            self.position + offset,
+            Radians(self.rotation.0 + angle),
+            D::one() / self.zoom,
+        )
+    }
+    pub fn projection(&self) -> Orthographic<D> {
+        let size = self.viewport.rect.size();
+        Orthographic::centered(Vector2::new(size.x, size.y))
+    }
+    pub fn pipeline(&self) -> ViewPipeline<D> {
+        ViewPipeline::new(self.view(), self.projection(), self.viewport)
+    }
+    /// Bounding rect of the world area covered by the viewport.
+    pub fn visible_rect(&self) -> Rect2<D, WorldSpace> {
+        let Some(screen_to_world) = self.pipeline().screen_to_world() else {
+            return Rect2::new(self.position, self.position);
+        };
+        let (min, max) = (self.viewport.rect.min(), self.viewport.rect.max());
+        let mut rect = Rect2::empty();
+        for corner in [
+            min,
+            Vector2::new(max.x, min.y),
+            max,
+            Vector2::new(min.x, max.y),
+        ] {
+            rect.add_point(&screen_to_world.transform_point(corner));
+        }
+        rect
+    }
+
+    /// Size of the visible rect without the shake.
+    fn visible_extents(&self) -> Vector2<D, WorldSpace> {
+        if !self.zoom.can_div_safe() {
+            return Vector2::new(D::zero(), D::zero());
+        }
+        let size = self.viewport.rect.size() / self.zoom;
+        let (sin, cos) = self.rotation.0.sin_cos();
+        let (sin, cos) = (sin.abs(), cos.abs());
+        Vector2::new(size.x * cos + size.y * sin, size.x * sin + size.y * cos)
+    }
+}
+
+/// Lower bound of the zoom limits.
+fn smallest_zoom<D>() -> D
+where
+    D: Decimal,
+{
+    D::one() / D::from_i32(1_000_000)
+}
+
+fn dead_zone_goal<D>(position: D, target: D, half: D) -> D
+where
+    D: Decimal,
+{
+    if target > position + half {
+        target - half
+    } else if target < position - half {
+        target + half
+    } else {
+        position
+    }
+}
+
+/// Smooth value noise in `[-1, 1]`, the same for the same inputs.
+fn noise<D>(seed: u32, time: D) -> D
+where
+    D: Decimal,
+{
+    let cell = time.floor();
+    let fract = time - cell;
+    let cell = cell.to_i32();
+    let a = lattice::<D>(seed, cell);
+    let b = lattice::<D>(seed, cell.wrapping_add(1));
+    let two = D::one() + D::one();
+    let three = two + D::one();
+    let smooth = fract * fract * (three - two * fract);
+    a + (b - a) * smooth
+}
+fn lattice<D>(seed: u32, cell: i32) -> D
+where
+    D: Decimal,
+{
+    let mut hash = (cell as u32).wrapping_mul(0x9E37_79B1) ^ seed.wrapping_mul(0x85EB_CA6B);
+    hash ^= hash >> 15;
+    hash = hash.wrapping_mul(0x2C1B_3C6D);
+    hash ^= hash >> 12;
+    hash = hash.wrapping_mul(0x297A_2D39);
+    hash ^= hash >> 15;
+    D::from_i32((hash >> 16) as i32 - 32768) / D::from_i32(32768)
+}
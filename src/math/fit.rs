@@ -0,0 +1,92 @@
+use crate::math::{Affine2, Decimal, Rect2, Unit, Vector2};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum FitMode {
+    /// Largest uniform scale that keeps the whole content visible.
+    #[default]
+    Contain,
+    /// Smallest uniform scale that covers the whole container.
+    Cover,
+    /// Non-uniform scale that matches the container exactly.
+    Fill,
+    /// Keeps the content at its original size.
+    None,
+    /// Largest whole number scale that keeps the whole content visible, never
+    /// below one. The fitted origin is rounded down to a whole unit.
+    IntegerScale,
+}
+
+/// Rect the content occupies once fitted inside `container`.
+///
+/// `align` is the normalized point of the container the content is aligned
+/// to, `(0.5, 0.5)` centers it and `(0, 0)` puts it at the top-left corner.
+pub fn fit<D, F, T>(
+    content_size: Vector2<D, F>,
+    container: &Rect2<D, T>,
+    mode: FitMode,
+    align: Vector2<D>,
+) -> Rect2<D, T>
+where
+    D: Decimal,
+    F: Unit,
+    T: Unit,
+{
+    let scale = fit_scale(content_size, container, mode);
+    let size = Vector2::new(content_size.x * scale.x, content_size.y * scale.y);
+    let slack = container.size() - size;
+    let mut origin = container.min() + Vector2::new(slack.x * align.x, slack.y * align.y);
+    if mode == FitMode::IntegerScale {
+        // Whole pixels only, otherwise the scaled content is sampled between
+        // texels again.
+        origin = Vector2::new(origin.x.floor(), origin.y.floor());
+    }
+    Rect2::from_size(origin, size)
+}
+/// Transform mapping content space, with the content starting at its origin,
+/// into the rect returned by `fit`.
+pub fn fit_transform<D, F, T>(
+    content_size: Vector2<D, F>,
+    container: &Rect2<D, T>,
+    mode: FitMode,
+    align: Vector2<D>,
+) -> Affine2<D, F, T>
+where
+    D: Decimal,
+    F: Unit,
+    T: Unit,
+{
+    let scale = fit_scale(content_size, container, mode);
+    let origin = fit(content_size, container, mode, align).min();
+    Affine2::new(scale.x, D::zero(), D::zero(), scale.y, origin.x, origin.y)
+}
+
+fn fit_scale<D, F, T>(
+    content_size: Vector2<D, F>,
+    container: &Rect2<D, T>,
+    mode: FitMode,
+) -> Vector2<D>
+where
+    D: Decimal,
+    F: Unit,
+    T: Unit,
+{
+    let available = container.size();
+    let ratio = |available: D, content: D| {
+        if content.can_div_safe() {
+            available / content
+        } else {
+            D::one()
+        }
+    };
+    let x = ratio(available.x, content_size.x);
+    let y = ratio(available.y, content_size.y);
+    let uniform = |scale: D| Vector2::new(scale, scale);
+    match mode {
+        FitMode::Contain => uniform(x.min(y)),
+        FitMode::Cover => uniform(x.max(y)),
+        FitMode::Fill => Vector2::new(x, y),
+        FitMode::None => uniform(D::one()),
+        FitMode::IntegerScale => uniform(x.min(y).floor().max(D::one())),
+    }
+}
@@ -0,0 +1,313 @@
+use std::cell::Cell;
+
+use crate::math::{Affine2, Decimal, WorldSpace};
+
+/// Handle to a node of a `TransformHierarchy`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodeId(usize);
+
+#[derive(Debug, Clone)]
+struct Node<D, T>
+where
+    D: Decimal,
+{
+    data: T,
+    local: Affine2<D>,
+    world: Cell<Affine2<D, (), WorldSpace>>,
+    dirty: Cell<bool>,
+    parent: Option<usize>,
+    children: Vec<usize>,
+}
+
+/// Tree of transforms relative to their parent, world transforms are computed
+/// on demand and cached until the node or one of its ancestors changes.
+///
+/// A dirty node always has dirty descendants, so invalidation can stop at the
+/// first node that is already dirty.
+#[derive(Debug, Clone)]
+pub struct TransformHierarchy<D, T>
+where
+    D: Decimal,
+{
+    nodes: Vec<Option<Node<D, T>>>,
+    free: Vec<usize>,
+    roots: Vec<usize>,
+    len: usize,
+}
+impl<D, T> Default for TransformHierarchy<D, T>
+where
+    D: Decimal,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<D, T> TransformHierarchy<D, T>
+where
+    D: Decimal,
+{
+    pub fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            free: Vec::new(),
+            roots: Vec::new(),
+            len: 0,
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    pub fn contains(&self, id: NodeId) -> bool {
+        self.node(id.0).is_some()
+    }
+
+    /// Adds a node under `parent`, or as a root. Returns `None` when the parent
+    /// doesn't exist.
+    pub fn insert(&mut self, parent: Option<NodeId>, local: Affine2<D>, data: T) -> Option<NodeId> {
+        if let Some(parent) = parent {
+            self.node(parent.0)?;
+        }
+        let node = Node {
+            data,
+            local,
+            world: Cell::new(Affine2::identity()),
+            dirty: Cell::new(true),
+            parent: parent.map(|parent| parent.0),
+            children: Vec::new(),
+        };
+        let idx = match self.free.pop() {
+            Some(idx) => {
+                self.nodes[idx] = Some(node);
+                idx
+            }
+            None => {
+                self.nodes.push(Some(node));
+                self.nodes.len() - 1
+            }
+        };
+        match parent {
+            Some(parent) => self.node_mut(parent.0).children.push(idx),
+            None => self.roots.push(idx),
+        }
+        self.len += 1;
+        Some(NodeId(idx))
+    }
+    /// Removes a node, its children move to its parent and keep their world
+    /// transform.
+    pub fn remove(&mut self, id: NodeId) -> Option<T> {
+        let parent = self.node(id.0)?.parent;
+        for child in self.node_mut(id.0).children.clone() {
+            let world = self.world(NodeId(child)).unwrap_or_else(Affine2::identity);
+            let local = match parent {
+                Some(parent) => self.local_for(parent, world),
+                None => Some(untyped(world)),
+            };
+            self.attach(child, parent);
+            if let Some(local) = local {
+                self.node_mut(child).local = local;
+            }
+            self.invalidate(child);
+        }
+        self.detach(id.0);
+        let node = self.nodes[id.0].take()?;
+        self.free.push(id.0);
+        self.len -= 1;
+        Some(node.data)
+    }
+
+    pub fn get(&self, id: NodeId) -> Option<&T> {
+        self.node(id.0).map(|node| &node.data)
+    }
+    pub fn get_mut(&mut self, id: NodeId) -> Option<&mut T> {
+        self.nodes
+            .get_mut(id.0)?
+            .as_mut()
+            .map(|node| &mut node.data)
+    }
+    pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.node(id.0)?.parent.map(NodeId)
+    }
+
+    pub fn local(&self, id: NodeId) -> Option<Affine2<D>> {
+        self.node(id.0).map(|node| node.local)
+    }
+    /// Replaces the transform of a node relative to its parent.
+    pub fn set_local(&mut self, id: NodeId, local: Affine2<D>) -> bool {
+        let Some(node) = self.nodes.get_mut(id.0).and_then(Option::as_mut) else {
+            return false;
+        };
+        node.local = local;
+        self.invalidate(id.0);
+        true
+    }
+    /// Transform from the node space to the world, computed on demand.
+    pub fn world(&self, id: NodeId) -> Option<Affine2<D, (), WorldSpace>> {
+        let node = self.node(id.0)?;
+        if node.dirty.get() {
+            let parent = match node.parent {
+                Some(parent) => self.world(NodeId(parent))?,
+                None => Affine2::identity(),
+            };
+            node.world.set(parent * node.local);
+            node.dirty.set(false);
+        }
+        Some(node.world.get())
+    }
+    /// Moves the local transform so the node ends up with the given world
+    /// transform. Fails when the parent transform can't be inverted.
+    pub fn set_world(&mut self, id: NodeId, world: Affine2<D, (), WorldSpace>) -> bool {
+        let Some(node) = self.node(id.0) else {
+            return false;
+        };
+        let local = match node.parent {
+            Some(parent) => self.local_for(parent, world),
+            None => Some(untyped(world)),
+        };
+        match local {
+            Some(local) => self.set_local(id, local),
+            None => false,
+        }
+    }
+
+    /// Moves a node under `parent`, or to the roots, keeping its world
+    /// transform. Fails when the parent doesn't exist, is the node itself or
+    /// one of its descendants, or can't be inverted.
+    pub fn reparent(&mut self, id: NodeId, parent: Option<NodeId>) -> bool {
+        if !self.contains(id) {
+            return false;
+        }
+        let Some(world) = self.world(id) else {
+            return false;
+        };
+        let local = match parent {
+            Some(parent) => {
+                if !self.contains(parent) || self.ancestors_and_self(parent).any(|idx| idx == id.0)
+                {
+                    return false;
+                }
+                match self.local_for(parent.0, world) {
+                    Some(local) => local,
+                    None => return false,
+                }
+            }
+            None => untyped(world),
+        };
+        self.attach(id.0, parent.map(|parent| parent.0));
+        self.set_local(id, local)
+    }
+
+    pub fn roots(&self) -> impl Iterator<Item = NodeId> + '_ {
+        self.roots.iter().map(|&idx| NodeId(idx))
+    }
+    pub fn children(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.node(id.0)
+            .into_iter()
+            .flat_map(|node| node.children.iter().map(|&idx| NodeId(idx)))
+    }
+    /// Parent, grand parent and so on up to the root.
+    pub fn ancestors(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.ancestors_and_self(id).skip(1).map(NodeId)
+    }
+    /// Descendants of a node in depth first order, parents before children.
+    pub fn descendants(&self, id: NodeId) -> Descendants<'_, D, T> {
+        let mut stack = Vec::new();
+        if let Some(node) = self.node(id.0) {
+            stack.extend(node.children.iter().rev());
+        }
+        Descendants {
+            hierarchy: self,
+            stack,
+        }
+    }
+    /// Every node in depth first order, roots first.
+    pub fn iter(&self) -> Descendants<'_, D, T> {
+        Descendants {
+            hierarchy: self,
+            stack: self.roots.iter().rev().copied().collect(),
+        }
+    }
+
+    fn node(&self, idx: usize) -> Option<&Node<D, T>> {
+        self.nodes.get(idx)?.as_ref()
+    }
+    fn node_mut(&mut self, idx: usize) -> &mut Node<D, T> {
+        self.nodes[idx]
+            .as_mut()
+            .expect("node handles are checked before use")
+    }
+    fn ancestors_and_self(&self, id: NodeId) -> impl Iterator<Item = usize> + '_ {
+        std::iter::successors(self.node(id.0).map(|_| id.0), |&idx| self.node(idx)?.parent)
+    }
+    fn local_for(&self, parent: usize, world: Affine2<D, (), WorldSpace>) -> Option<Affine2<D>> {
+        let parent = self.world(NodeId(parent))?.inverse()?;
+        Some(parent * world)
+    }
+    fn detach(&mut self, idx: usize) {
+        let siblings = match self.node_mut(idx).parent.take() {
+            Some(parent) => &mut self.node_mut(parent).children,
+            None => &mut self.roots,
+        };
+        siblings.retain(|&sibling| sibling != idx);
+    }
+    fn attach(&mut self, idx: usize, parent: Option<usize>) {
+        self.detach(idx);
+        self.node_mut(idx).parent = parent;
+        match parent {
+            Some(parent) => self.node_mut(parent).children.push(idx),
+            None => self.roots.push(idx),
+        }
+    }
+    fn invalidate(&self, idx: usize) {
+        let mut stack = vec![idx];
+        while let Some(idx) = stack.pop() {
+            let Some(node) = self.node(idx) else {
+                continue;
+            };
+            node.dirty.set(true);
+            stack.extend(
+                node.children
+                    .iter()
+                    .copied()
+                    .filter(|&child| self.node(child).is_some_and(|child| !child.dirty.get())),
+            );
+        }
+    }
+}
+
+/// Depth first iterator over a `TransformHierarchy`.
+#[derive(Debug)]
+pub struct Descendants<'a, D, T>
+where
+    D: Decimal,
+{
+    hierarchy: &'a TransformHierarchy<D, T>,
+    stack: Vec<usize>,
+}
+impl<'a, D, T> Iterator for Descendants<'a, D, T>
+where
+    D: Decimal,
+{
+    type Item = (NodeId, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let idx = self.stack.pop()?;
+        let node = self.hierarchy.node(idx)?;
+        self.stack.extend(node.children.iter().rev());
+        Some((NodeId(idx), &node.data))
+    }
+}
+
+fn untyped<D>(world: Affine2<D, (), WorldSpace>) -> Affine2<D>
+where
+    D: Decimal,
+{
+    Affine2::new(
+        world.m00, world.m01, world.m10, world.m11, world.m20, world.m21,
+    )
+}
@@ -0,0 +1,207 @@
+use std::{marker::PhantomData, ops::Mul};
+
+use crate::math::{Affine2, Angle, Decimal, Rotation2, Unit, Vector2};
+
+/// Rotation followed by a translation, keeps distances and angles.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Isometry2<D, F = (), T = ()>
+where
+    D: Decimal,
+    F: Unit,
+    T: Unit,
+{
+    pub rotation: Rotation2<D>,
+    pub translation: Vector2<D, T>,
+    _phantom: PhantomData<F>,
+}
+impl<D, F, T> Isometry2<D, F, T>
+where
+    D: Decimal,
+    F: Unit,
+    T: Unit,
+{
+    pub fn new<A>(rotation: A, translation: Vector2<D, T>) -> Self
+    where
+        A: Angle<D>,
+    {
+        Self::from_parts(Rotation2::new(rotation), translation)
+    }
+    pub fn from_parts(rotation: Rotation2<D>, translation: Vector2<D, T>) -> Self {
+        Self {
+            rotation,
+            translation,
+            _phantom: PhantomData,
+        }
+    }
+    pub fn identity() -> Self {
+        Self::from_translation(Vector2::new(D::zero(), D::zero()))
+    }
+    pub fn from_translation(translation: Vector2<D, T>) -> Self {
+        Self::from_parts(Rotation2::identity(), translation)
+    }
+    pub fn from_rotation<A>(rotation: A) -> Self
+    where
+        A: Angle<D>,
+    {
+        Self::new(rotation, Vector2::new(D::zero(), D::zero()))
+    }
+
+    pub fn transform_point(&self, point: Vector2<D, F>) -> Vector2<D, T> {
+        self.transform_vector(point) + self.translation
+    }
+    /// Rotates a direction, ignoring the translation.
+    pub fn transform_vector(&self, vector: Vector2<D, F>) -> Vector2<D, T> {
+        let rotated = self.rotation.rotate(vector);
+        Vector2::new(rotated.x, rotated.y)
+    }
+    /// Exact inverse, no division involved.
+    pub fn inverse(&self) -> Isometry2<D, T, F> {
+        let rotation = self.rotation.inverse();
+        let translation = rotation.rotate(self.translation);
+        Isometry2::from_parts(rotation, Vector2::new(-translation.x, -translation.y))
+    }
+    pub fn to_affine(&self) -> Affine2<D, F, T> {
+        Similarity2::from(*self).to_affine()
+    }
+}
+impl<D, F, T, NT> Mul<Isometry2<D, F, T>> for Isometry2<D, T, NT>
+where
+    D: Decimal,
+    F: Unit,
+    T: Unit,
+    NT: Unit,
+{
+    type Output = Isometry2<D, F, NT>;
+
+    fn mul(self, rhs: Isometry2<D, F, T>) -> Self::Output {
+        let translation = self.transform_vector(rhs.translation);
+        Isometry2::from_parts(self.rotation * rhs.rotation, translation + self.translation)
+    }
+}
+impl<D, F, T> From<Isometry2<D, F, T>> for Affine2<D, F, T>
+where
+    D: Decimal,
+    F: Unit,
+    T: Unit,
+{
+    fn from(value: Isometry2<D, F, T>) -> Self {
+        value.to_affine()
+    }
+}
+
+/// Uniform scale, then rotation, then translation. Keeps angles and scales
+/// every distance by the same factor.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Similarity2<D, F = (), T = ()>
+where
+    D: Decimal,
+    F: Unit,
+    T: Unit,
+{
+    pub isometry: Isometry2<D, F, T>,
+    pub scale: D,
+}
+impl<D, F, T> Similarity2<D, F, T>
+where
+    D: Decimal,
+    F: Unit,
+    T: Unit,
+{
+    pub fn new<A>(scale: D, rotation: A, translation: Vector2<D, T>) -> Self
+    where
+        A: Angle<D>,
+    {
+        Self {
+            isometry: Isometry2::new(rotation, translation),
+            scale,
+        }
+    }
+    pub fn identity() -> Self {
+        Isometry2::identity().into()
+    }
+    pub fn from_scale(scale: D) -> Self {
+        Self {
+            isometry: Isometry2::identity(),
+            scale,
+        }
+    }
+
+    pub fn rotation(&self) -> Rotation2<D> {
+        self.isometry.rotation
+    }
+    pub fn translation(&self) -> Vector2<D, T> {
+        self.isometry.translation
+    }
+    pub fn transform_point(&self, point: Vector2<D, F>) -> Vector2<D, T> {
+        self.isometry
+            .transform_point(Vector2::new(point.x * self.scale, point.y * self.scale))
+    }
+    /// Scales and rotates a direction, ignoring the translation.
+    pub fn transform_vector(&self, vector: Vector2<D, F>) -> Vector2<D, T> {
+        self.isometry
+            .transform_vector(Vector2::new(vector.x * self.scale, vector.y * self.scale))
+    }
+    /// `None` when the scale is zero.
+    pub fn inverse(&self) -> Option<Similarity2<D, T, F>> {
+        if !self.scale.can_div_safe() {
+            return None;
+        }
+        let scale = D::one() / self.scale;
+        let mut isometry = self.isometry.inverse();
+        isometry.translation *= scale;
+        Some(Similarity2 { isometry, scale })
+    }
+    #[rustfmt::skip]
+    pub fn to_affine(&self) -> Affine2<D, F, T> {
+        let cos = self.isometry.rotation.cos() * self.scale;
+        let sin = self.isometry.rotation.sin() * self.scale;
+        let translation = self.isometry.translation;
+        Affine2::new(
+            cos, sin,
+            -sin, cos,
+            translation.x, translation.y,
+        )
+    }
+}
+impl<D, F, T, NT> Mul<Similarity2<D, F, T>> for Similarity2<D, T, NT>
+where
+    D: Decimal,
+    F: Unit,
+    T: Unit,
+    NT: Unit,
+{
+    type Output = Similarity2<D, F, NT>;
+
+    fn mul(self, mut rhs: Similarity2<D, F, T>) -> Self::Output {
+        rhs.isometry.translation *= self.scale;
+        Similarity2 {
+            isometry: self.isometry * rhs.isometry,
+            scale: self.scale * rhs.scale,
+        }
+    }
+}
+impl<D, F, T> From<Isometry2<D, F, T>> for Similarity2<D, F, T>
+where
+    D: Decimal,
+    F: Unit,
+    T: Unit,
+{
+    fn from(isometry: Isometry2<D, F, T>) -> Self {
+        Self {
+            isometry,
+            scale: D::one(),
+        }
+    }
+}
+impl<D, F, T> From<Similarity2<D, F, T>> for Affine2<D, F, T>
+where
+    D: Decimal,
+    F: Unit,
+    T: Unit,
+{
+    fn from(value: Similarity2<D, F, T>) -> Self {
+        value.to_affine()
+    }
+}
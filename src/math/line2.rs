@@ -1,6 +1,93 @@
-use crate::math::{Integer, Unit, Vector2};
+use crate::math::{Decimal, Integer, Rect2, Unit, Vector, Vector2};
 
-pub struct Line2<I, U>(Vector2<I, U>, Vector2<I, U>)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Line2<I, U = ()>(pub Vector2<I, U>, pub Vector2<I, U>)
 where
     I: Integer,
     U: Unit;
+impl<I, U> Line2<I, U>
+where
+    I: Integer,
+    U: Unit,
+{
+    pub fn new(start: Vector2<I, U>, end: Vector2<I, U>) -> Self {
+        Self(start, end)
+    }
+
+    #[inline]
+    pub fn start(&self) -> Vector2<I, U> {
+        self.0
+    }
+    #[inline]
+    pub fn end(&self) -> Vector2<I, U> {
+        self.1
+    }
+    pub fn point_at(&self, t: I::Decimal) -> Vector2<I::Decimal, U> {
+        self.0.lerp(self.1, t)
+    }
+}
+impl<D, U> Line2<D, U>
+where
+    D: Decimal,
+    U: Unit,
+{
+    /// Clips the segment against `rect` using Liang–Barsky.
+    pub fn clip(&self, rect: &Rect2<D, U>) -> Option<LineClip<D, U>> {
+        rect.clip_segment(self.0, self.1)
+    }
+}
+
+/// Part of a segment that lies inside a rect, `t_enter` and `t_exit` are the
+/// parameters along the original segment where the clipped one starts and
+/// ends.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LineClip<D, U = ()>
+where
+    D: Decimal,
+    U: Unit,
+{
+    pub line: Line2<D, U>,
+    pub t_enter: D,
+    pub t_exit: D,
+}
+
+impl<D, U> Rect2<D, U>
+where
+    D: Decimal,
+    U: Unit,
+{
+    /// Clips the segment going from `start` to `end` against the rect using
+    /// Liang–Barsky, `None` if no part of it is inside.
+    pub fn clip_segment(&self, start: Vector2<D, U>, end: Vector2<D, U>) -> Option<LineClip<D, U>> {
+        let delta = end - start;
+        let mut t_enter = D::zero();
+        let mut t_exit = D::one();
+        for (p, q) in [
+            (-delta.x, start.x - self.min().x),
+            (delta.x, self.max().x - start.x),
+            (-delta.y, start.y - self.min().y),
+            (delta.y, self.max().y - start.y),
+        ] {
+            if p == D::zero() {
+                if q < D::zero() {
+                    return None;
+                }
+                continue;
+            }
+            let t = q / p;
+            if p < D::zero() {
+                t_enter = t_enter.max(t);
+            } else {
+                t_exit = t_exit.min(t);
+            }
+            if t_enter > t_exit {
+                return None;
+            }
+        }
+        Some(LineClip {
+            line: Line2(start + delta * t_enter, start + delta * t_exit),
+            t_enter,
+            t_exit,
+        })
+    }
+}
@@ -0,0 +1,188 @@
+use std::{marker::PhantomData, ops::Mul};
+
+use crate::math::{Affine3, Decimal, Quaternion, Unit, Vector3};
+
+/// Projective transform in 3D, laid out like `Affine3` with an extra column
+/// holding the homogeneous w.
+#[derive(Debug, Clone, Copy)]
+pub struct Matrix4<D, F = (), T = ()>
+where
+    D: Decimal,
+    F: Unit,
+    T: Unit,
+{
+    pub m: [[D; 4]; 4],
+    _phantom: PhantomData<(F, T)>,
+}
+impl<D, F, T> Matrix4<D, F, T>
+where
+    D: Decimal,
+    F: Unit,
+    T: Unit,
+{
+    pub fn new(m: [[D; 4]; 4]) -> Self {
+        Self {
+            m,
+            _phantom: PhantomData,
+        }
+    }
+    pub fn identity() -> Self {
+        Affine3::identity().into()
+    }
+    pub fn from_translation(translation: Vector3<D, T>) -> Self {
+        Affine3::from_translation(translation).into()
+    }
+    pub fn from_rotation(rotation: Quaternion<D>) -> Self {
+        Affine3::from_rotation(rotation).into()
+    }
+    pub fn from_scale(scale: D) -> Self {
+        Affine3::from_scale(scale).into()
+    }
+    pub fn from_nonuniform_scale(scale: Vector3<D, T>) -> Self {
+        Affine3::from_nonuniform_scale(scale).into()
+    }
+
+    /// Transforms `(x, y, z, w)` without dividing by the resulting w.
+    pub fn transform_homogeneous(&self, point: [D; 4]) -> [D; 4] {
+        let mut out = [D::zero(); 4];
+        for (row, value) in self.m.iter().zip(point) {
+            for (out, entry) in out.iter_mut().zip(row) {
+                *out += value * *entry;
+            }
+        }
+        out
+    }
+    /// Transforms a point and divides by w, `None` when it lands at infinity.
+    pub fn transform_point(&self, point: Vector3<D, F>) -> Option<Vector3<D, T>> {
+        let [x, y, z, w] = self.transform_homogeneous([point.x, point.y, point.z, D::one()]);
+        if !w.can_div_safe() {
+            return None;
+        }
+        Some(Vector3::new(x / w, y / w, z / w))
+    }
+    /// Transforms a direction, ignoring the translation and the projection.
+    pub fn transform_vector(&self, vector: Vector3<D, F>) -> Vector3<D, T> {
+        let [x, y, z, _] = self.transform_homogeneous([vector.x, vector.y, vector.z, D::zero()]);
+        Vector3::new(x, y, z)
+    }
+    pub fn transpose(&self) -> Self {
+        let mut m = self.m;
+        for (row, values) in m.iter_mut().enumerate() {
+            for (col, value) in values.iter_mut().enumerate() {
+                *value = self.m[col][row];
+            }
+        }
+        Self::new(m)
+    }
+    pub fn determinant(&self) -> D {
+        let (s, c) = self.minors();
+        s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+    }
+    /// Transform undoing this one, `None` when the matrix is singular.
+    pub fn inverse(&self) -> Option<Matrix4<D, T, F>> {
+        let det = self.determinant();
+        if !det.can_div_safe() {
+            return None;
+        }
+        let (s, c) = self.minors();
+        let m = &self.m;
+        let inv = D::one() / det;
+        Some(Matrix4::new([
+            [
+                (m[1][1] * c[5] - m[1][2] * c[4] + m[1][3] * c[3]) * inv,
+                (-m[0][1] * c[5] + m[0][2] * c[4] - m[0][3] * c[3]) * inv,
+                (m[3][1] * s[5] - m[3][2] * s[4] + m[3][3] * s[3]) * inv,
+                (-m[2][1] * s[5] + m[2][2] * s[4] - m[2][3] * s[3]) * inv,
+            ],
+            [
+                (-m[1][0] * c[5] + m[1][2] * c[2] - m[1][3] * c[1]) * inv,
+                (m[0][0] * c[5] - m[0][2] * c[2] + m[0][3] * c[1]) * inv,
+                (-m[3][0] * s[5] + m[3][2] * s[2] - m[3][3] * s[1]) * inv,
+                (m[2][0] * s[5] - m[2][2] * s[2] + m[2][3] * s[1]) * inv,
+            ],
+            [
+                (m[1][0] * c[4] - m[1][1] * c[2] + m[1][3] * c[0]) * inv,
+                (-m[0][0] * c[4] + m[0][1] * c[2] - m[0][3] * c[0]) * inv,
+                (m[3][0] * s[4] - m[3][1] * s[2] + m[3][3] * s[0]) * inv,
+                (-m[2][0] * s[4] + m[2][1] * s[2] - m[2][3] * s[0]) * inv,
+            ],
+            [
+                (-m[1][0] * c[3] + m[1][1] * c[1] - m[1][2] * c[0]) * inv,
+                (m[0][0] * c[3] - m[0][1] * c[1] + m[0][2] * c[0]) * inv,
+                (-m[3][0] * s[3] + m[3][1] * s[1] - m[3][2] * s[0]) * inv,
+                (m[2][0] * s[3] - m[2][1] * s[1] + m[2][2] * s[0]) * inv,
+            ],
+        ]))
+    }
+
+    /// 2x2 minors of the top and bottom halves, shared by the determinant and
+    /// the inverse.
+    fn minors(&self) -> ([D; 6], [D; 6]) {
+        let m = &self.m;
+        let minor = |r0: usize, r1: usize, c0: usize, c1: usize| {
+            m[r0][c0] * m[r1][c1] - m[r1][c0] * m[r0][c1]
+        };
+        (
+            [
+                minor(0, 1, 0, 1),
+                minor(0, 1, 0, 2),
+                minor(0, 1, 0, 3),
+                minor(0, 1, 1, 2),
+                minor(0, 1, 1, 3),
+                minor(0, 1, 2, 3),
+            ],
+            [
+                minor(2, 3, 0, 1),
+                minor(2, 3, 0, 2),
+                minor(2, 3, 0, 3),
+                minor(2, 3, 1, 2),
+                minor(2, 3, 1, 3),
+                minor(2, 3, 2, 3),
+            ],
+        )
+    }
+}
+impl<D, F, T, NT> Mul<Matrix4<D, F, T>> for Matrix4<D, T, NT>
+where
+    D: Decimal,
+    F: Unit,
+    T: Unit,
+    NT: Unit,
+{
+    type Output = Matrix4<D, F, NT>;
+
+    fn mul(self, rhs: Matrix4<D, F, T>) -> Self::Output {
+        let mut m = [[D::zero(); 4]; 4];
+        for (row, rhs_row) in m.iter_mut().zip(rhs.m) {
+            *row = self.transform_homogeneous(rhs_row);
+        }
+        Matrix4::new(m)
+    }
+}
+impl<D, F, T> From<Affine3<D, F, T>> for Matrix4<D, F, T>
+where
+    D: Decimal,
+    F: Unit,
+    T: Unit,
+{
+    fn from(value: Affine3<D, F, T>) -> Self {
+        let [x, y, z, t] = value.m;
+        let (zero, one) = (D::zero(), D::one());
+        Matrix4::new([
+            [x[0], x[1], x[2], zero],
+            [y[0], y[1], y[2], zero],
+            [z[0], z[1], z[2], zero],
+            [t[0], t[1], t[2], one],
+        ])
+    }
+}
+impl<D, F, T> From<Matrix4<D, F, T>> for [[D; 4]; 4]
+where
+    D: Decimal,
+    F: Unit,
+    T: Unit,
+{
+    fn from(value: Matrix4<D, F, T>) -> Self {
+        value.m
+    }
+}
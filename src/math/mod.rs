@@ -1,9 +1,23 @@
 pub mod angles;
 pub mod bezier;
+pub mod camera;
+pub mod fit;
+pub mod hierarchy;
+pub mod isometry;
 pub mod line2;
+pub mod matrix4;
+pub mod projection;
+pub mod projective;
+pub mod quaternion;
 pub mod rect;
+pub mod rotation;
+pub mod rounded_rect;
+pub mod scale;
 pub mod transform2;
+pub mod transform3;
 pub mod vec2;
+pub mod vec3;
+pub mod viewport;
 
 use core::{f32, f64};
 use std::{
@@ -14,9 +28,24 @@ use std::{
 use crate::define_spaces;
 pub use crate::math::{
     angles::{Angle, Degrees, Radians},
-    rect::{Rect2, Rect2d, Rect2f, Rect2i, Rect2u},
-    transform2::Affine2,
+    camera::Camera2,
+    fit::{FitMode, fit, fit_transform},
+    hierarchy::{Descendants, NodeId, TransformHierarchy},
+    isometry::{Isometry2, Similarity2},
+    line2::{Line2, LineClip},
+    matrix4::Matrix4,
+    projection::{DepthRange, Handedness, LeftHanded, NegativeOneToOne, RightHanded, ZeroToOne},
+    projective::Projective2,
+    quaternion::Quaternion,
+    rect::{CornerData, EdgeInsets, Rect2, Rect2d, Rect2f, Rect2i, Rect2u},
+    rotation::Rotation2,
+    rounded_rect::RoundedRect2,
+    scale::{DevicePixelRatio, RoundingPolicy, ScaleFactor},
+    transform2::{Affine2, AffineParts},
+    transform3::Affine3,
     vec2::{Vector2, Vector2d, Vector2f, Vector2i, Vector2u},
+    vec3::{Vector3, Vector3d, Vector3f, Vector3i, Vector3u},
+    viewport::{ClipOrientation, Orthographic, ViewPipeline, Viewport},
 };
 
 pub trait Decimal: Clone + Copy + Integer<Decimal = Self> + Neg<Output = Self> {
@@ -26,10 +55,17 @@ pub trait Decimal: Clone + Copy + Integer<Decimal = Self> + Neg<Output = Self> {
     fn to_degrees(self) -> Self;
 
     fn sqrt(&self) -> Self;
+    fn abs(self) -> Self;
     fn cos(self) -> Self;
     fn sin(self) -> Self;
     fn sin_cos(self) -> (Self, Self);
     fn atan2(self, other: Self) -> Self;
+    fn exp(self) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    fn to_i32(self) -> i32;
+    fn from_i32(value: i32) -> Self;
 
     fn is_number(self) -> bool;
     fn can_div_safe(self) -> bool;
@@ -51,6 +87,9 @@ impl Decimal for f32 {
     fn sqrt(&self) -> Self {
         f32::sqrt(*self)
     }
+    fn abs(self) -> Self {
+        f32::abs(self)
+    }
     fn cos(self) -> Self {
         f32::cos(self)
     }
@@ -63,6 +102,24 @@ impl Decimal for f32 {
     fn atan2(self, other: Self) -> Self {
         self.atan2(other)
     }
+    fn exp(self) -> Self {
+        f32::exp(self)
+    }
+    fn floor(self) -> Self {
+        f32::floor(self)
+    }
+    fn ceil(self) -> Self {
+        f32::ceil(self)
+    }
+    fn round(self) -> Self {
+        f32::round(self)
+    }
+    fn to_i32(self) -> i32 {
+        self as i32
+    }
+    fn from_i32(value: i32) -> Self {
+        value as f32
+    }
 
     fn is_number(self) -> bool {
         !(self.is_nan() || self.is_infinite())
@@ -88,6 +145,9 @@ impl Decimal for f64 {
     fn sqrt(&self) -> Self {
         f64::sqrt(*self)
     }
+    fn abs(self) -> Self {
+        f64::abs(self)
+    }
     fn cos(self) -> Self {
         f64::cos(self)
     }
@@ -100,6 +160,24 @@ impl Decimal for f64 {
     fn atan2(self, other: Self) -> Self {
         self.atan2(other)
     }
+    fn exp(self) -> Self {
+        f64::exp(self)
+    }
+    fn floor(self) -> Self {
+        f64::floor(self)
+    }
+    fn ceil(self) -> Self {
+        f64::ceil(self)
+    }
+    fn round(self) -> Self {
+        f64::round(self)
+    }
+    fn to_i32(self) -> i32 {
+        self as i32
+    }
+    fn from_i32(value: i32) -> Self {
+        value as f64
+    }
 
     fn is_number(self) -> bool {
         !(self.is_nan() || self.is_infinite())
@@ -133,6 +211,12 @@ pub trait Integer:
     fn splat<V: Vector<Self>>(self) -> V;
 
     fn to_precise(self) -> Self::Decimal;
+    /// Addition clamped to the representable range, plain addition for
+    /// floats.
+    fn saturating_add(self, other: Self) -> Self;
+    /// Subtraction clamped to the representable range, plain subtraction for
+    /// floats.
+    fn saturating_sub(self, other: Self) -> Self;
 
     fn min(self, other: Self) -> Self {
         if self > other { other } else { self }
@@ -171,6 +255,12 @@ impl Integer for f32 {
     fn to_precise(self) -> f32 {
         self
     }
+    fn saturating_add(self, other: Self) -> Self {
+        self + other
+    }
+    fn saturating_sub(self, other: Self) -> Self {
+        self - other
+    }
 }
 impl Integer for f64 {
     type Decimal = Self;
@@ -192,6 +282,12 @@ impl Integer for f64 {
     fn to_precise(self) -> f64 {
         self
     }
+    fn saturating_add(self, other: Self) -> Self {
+        self + other
+    }
+    fn saturating_sub(self, other: Self) -> Self {
+        self - other
+    }
 }
 
 macro_rules! impl_integer_for_ints {
@@ -217,6 +313,12 @@ macro_rules! impl_integer_for_ints {
                 fn to_precise(self) -> f32 {
                     self as f32
                 }
+                fn saturating_add(self, other: Self) -> Self {
+                    <$t>::saturating_add(self, other)
+                }
+                fn saturating_sub(self, other: Self) -> Self {
+                    <$t>::saturating_sub(self, other)
+                }
             }
         )*
     };
@@ -246,6 +348,12 @@ macro_rules! impl_integer_for_64 {
                 fn to_precise(self) -> f64 {
                     self as f64
                 }
+                fn saturating_add(self, other: Self) -> Self {
+                    <$t>::saturating_add(self, other)
+                }
+                fn saturating_sub(self, other: Self) -> Self {
+                    <$t>::saturating_sub(self, other)
+                }
             }
         )*
     };
@@ -261,7 +369,9 @@ define_spaces!(
     ViewSpace,
     ClipSpace,
     TexelSpace,
-    ScreenSpace
+    ScreenSpace,
+    LogicalSpace,
+    PhysicalSpace
 );
 
 pub trait Vector<I>
@@ -0,0 +1,171 @@
+use crate::math::{Angle, ClipSpace, Decimal, Matrix4, ViewSpace};
+
+/// Direction the view looks along in `ViewSpace`, with x to the right and y
+/// up.
+pub trait Handedness {
+    /// Sign of the z axis pointing away from the viewer.
+    fn forward<D: Decimal>() -> D;
+}
+/// The view looks along +z, like the inverse of `Affine3::look_at`, Direct3D
+/// style.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LeftHanded;
+/// The view looks along -z, OpenGL style.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RightHanded;
+impl Handedness for LeftHanded {
+    fn forward<D: Decimal>() -> D {
+        D::one()
+    }
+}
+impl Handedness for RightHanded {
+    fn forward<D: Decimal>() -> D {
+        -D::one()
+    }
+}
+
+/// Range of the normalized depth in `ClipSpace` after the perspective divide.
+pub trait DepthRange {
+    /// Depth of the near and far planes.
+    fn range<D: Decimal>() -> (D, D);
+}
+/// Depth from -1 to 1, OpenGL style.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NegativeOneToOne;
+/// Depth from 0 to 1, Direct3D, Metal, Vulkan and WebGPU style.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ZeroToOne;
+impl DepthRange for NegativeOneToOne {
+    fn range<D: Decimal>() -> (D, D) {
+        (-D::one(), D::one())
+    }
+}
+impl DepthRange for ZeroToOne {
+    fn range<D: Decimal>() -> (D, D) {
+        (D::zero(), D::one())
+    }
+}
+
+/// Projections for a view whose orientation is given by `H`, into a clip
+/// space whose depth range is given by `R`.
+///
+/// Every constructor returns `None` for zero sized volumes, perspective ones
+/// also need `0 < near < far`.
+impl<D> Matrix4<D, ViewSpace, ClipSpace>
+where
+    D: Decimal,
+{
+    /// Perspective projection with a vertical field of view `fov_y` and an
+    /// `aspect` ratio of width over height.
+    pub fn perspective<H, R, A>(fov_y: A, aspect: D, near: D, far: D) -> Option<Self>
+    where
+        H: Handedness,
+        R: DepthRange,
+        A: Angle<D>,
+    {
+        let (near_depth, far_depth) = R::range();
+        Self::frustum::<H, A>(fov_y, aspect, near, Some(far), near_depth, far_depth)
+    }
+    /// Perspective projection with the far plane at infinity.
+    pub fn perspective_infinite<H, R, A>(fov_y: A, aspect: D, near: D) -> Option<Self>
+    where
+        H: Handedness,
+        R: DepthRange,
+        A: Angle<D>,
+    {
+        let (near_depth, far_depth) = R::range();
+        Self::frustum::<H, A>(fov_y, aspect, near, None, near_depth, far_depth)
+    }
+    /// Perspective projection mapping the near plane to the far end of the
+    /// depth range, which spreads floating point precision evenly.
+    pub fn perspective_reversed<H, R, A>(fov_y: A, aspect: D, near: D, far: D) -> Option<Self>
+    where
+        H: Handedness,
+        R: DepthRange,
+        A: Angle<D>,
+    {
+        let (near_depth, far_depth) = R::range();
+        Self::frustum::<H, A>(fov_y, aspect, near, Some(far), far_depth, near_depth)
+    }
+    /// Reversed depth perspective projection with the far plane at infinity.
+    pub fn perspective_infinite_reversed<H, R, A>(fov_y: A, aspect: D, near: D) -> Option<Self>
+    where
+        H: Handedness,
+        R: DepthRange,
+        A: Angle<D>,
+    {
+        let (near_depth, far_depth) = R::range();
+        Self::frustum::<H, A>(fov_y, aspect, near, None, far_depth, near_depth)
+    }
+    /// Maps the box between the planes to clip space, `near` and `far` are
+    /// distances in front of the viewer.
+    pub fn orthographic<H, R>(left: D, right: D, bottom: D, top: D, near: D, far: D) -> Option<Self>
+    where
+        H: Handedness,
+        R: DepthRange,
+    {
+        let (width, height, depth) = (right - left, top - bottom, far - near);
+        if !width.can_div_safe() || !height.can_div_safe() || !depth.can_div_safe() {
+            return None;
+        }
+        let (near_depth, far_depth) = R::range::<D>();
+        let two = D::one() + D::one();
+        let zero = D::zero();
+        let scale_z = (far_depth - near_depth) / depth;
+        Some(Self::new([
+            [two / width, zero, zero, zero],
+            [zero, two / height, zero, zero],
+            [zero, zero, scale_z * H::forward(), zero],
+            [
+                -(right + left) / width,
+                -(top + bottom) / height,
+                near_depth - near * scale_z,
+                D::one(),
+            ],
+        ]))
+    }
+
+    /// Perspective projection mapping the distance `near` to `near_depth` and
+    /// `far`, or infinity, to `far_depth`.
+    fn frustum<H, A>(
+        fov_y: A,
+        aspect: D,
+        near: D,
+        far: Option<D>,
+        near_depth: D,
+        far_depth: D,
+    ) -> Option<Self>
+    where
+        H: Handedness,
+        A: Angle<D>,
+    {
+        let two = D::one() + D::one();
+        let (sin, cos) = (fov_y.radians() / two).sin_cos();
+        if !sin.can_div_safe() || !aspect.can_div_safe() || near <= D::zero() {
+            return None;
+        }
+        // The normalized depth is `a + b / distance`.
+        let (a, b) = match far {
+            Some(far) => {
+                let depth = far - near;
+                if far <= near || !depth.can_div_safe() {
+                    return None;
+                }
+                (
+                    (far_depth * far - near_depth * near) / depth,
+                    (near_depth - far_depth) * near * far / depth,
+                )
+            }
+            None => (far_depth, (near_depth - far_depth) * near),
+        };
+        let focal = cos / sin;
+        let forward = H::forward::<D>();
+        let zero = D::zero();
+        Some(Self::new([
+            [focal / aspect, zero, zero, zero],
+            [zero, focal, zero, zero],
+            [zero, zero, a * forward, forward],
+            [zero, zero, b, zero],
+        ]))
+    }
+}
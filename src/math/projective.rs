@@ -0,0 +1,203 @@
+use std::{marker::PhantomData, ops::Mul};
+
+use crate::math::{Affine2, Decimal, Rect2, Unit, Vector2};
+
+/// Projective transform in 2D, or homography, laid out like `Affine2` with an
+/// extra column holding the homogeneous w.
+///
+/// Maps straight lines to straight lines but not parallel lines to parallel
+/// ones, which makes it the transform between a plane and its image under a
+/// perspective view.
+#[derive(Debug, Clone, Copy)]
+pub struct Projective2<D, F = (), T = ()>
+where
+    D: Decimal,
+    F: Unit,
+    T: Unit,
+{
+    pub m: [[D; 3]; 3],
+    _phantom: PhantomData<(F, T)>,
+}
+impl<D, F, T> Projective2<D, F, T>
+where
+    D: Decimal,
+    F: Unit,
+    T: Unit,
+{
+    pub fn new(m: [[D; 3]; 3]) -> Self {
+        Self {
+            m,
+            _phantom: PhantomData,
+        }
+    }
+    pub fn identity() -> Self {
+        Affine2::identity().into()
+    }
+    /// Homography mapping each point of `from` to the point of `to` at the
+    /// same index. `None` when three of the points of either side are
+    /// collinear.
+    pub fn from_point_pairs(from: [Vector2<D, F>; 4], to: [Vector2<D, T>; 4]) -> Option<Self> {
+        let from = Projective2::<D, (), F>::from_unit_square(from)?;
+        let to = Projective2::<D, (), T>::from_unit_square(to)?;
+        Some(to * from.inverse()?)
+    }
+    /// Homography mapping the corners of `rect` to `quad`, which lists the
+    /// targets of the min corner, the `(max.x, min.y)` corner, the max corner
+    /// and the `(min.x, max.y)` corner in that order.
+    pub fn from_rect_to_quad(rect: &Rect2<D, F>, quad: [Vector2<D, T>; 4]) -> Option<Self> {
+        let (min, max) = (rect.min(), rect.max());
+        Self::from_point_pairs(
+            [
+                min,
+                Vector2::new(max.x, min.y),
+                max,
+                Vector2::new(min.x, max.y),
+            ],
+            quad,
+        )
+    }
+    /// Maps `(0, 0)`, `(1, 0)`, `(1, 1)` and `(0, 1)` to the quad corners.
+    fn from_unit_square(quad: [Vector2<D, T>; 4]) -> Option<Self> {
+        let [p0, p1, p2, p3] = quad;
+        let (dx1, dy1) = (p1.x - p2.x, p1.y - p2.y);
+        let (dx2, dy2) = (p3.x - p2.x, p3.y - p2.y);
+        let (dx3, dy3) = (p0.x - p1.x + p2.x - p3.x, p0.y - p1.y + p2.y - p3.y);
+        let det = dx1 * dy2 - dx2 * dy1;
+        if !det.can_div_safe() {
+            return None;
+        }
+        let g = (dx3 * dy2 - dx2 * dy3) / det;
+        let h = (dx1 * dy3 - dx3 * dy1) / det;
+        let projective = Self::new([
+            [p1.x - p0.x + g * p1.x, p1.y - p0.y + g * p1.y, g],
+            [p3.x - p0.x + h * p3.x, p3.y - p0.y + h * p3.y, h],
+            [p0.x, p0.y, D::one()],
+        ]);
+        projective
+            .determinant()
+            .can_div_safe()
+            .then_some(projective)
+    }
+
+    /// Transforms `(x, y, w)` without dividing by the resulting w.
+    pub fn transform_homogeneous(&self, point: [D; 3]) -> [D; 3] {
+        let mut out = [D::zero(); 3];
+        for (row, value) in self.m.iter().zip(point) {
+            for (out, entry) in out.iter_mut().zip(row) {
+                *out += value * *entry;
+            }
+        }
+        out
+    }
+    /// Transforms a point and divides by w, `None` when it lands at infinity.
+    pub fn transform_point(&self, point: Vector2<D, F>) -> Option<Vector2<D, T>> {
+        let [x, y, w] = self.transform_homogeneous([point.x, point.y, D::one()]);
+        if !w.can_div_safe() {
+            return None;
+        }
+        Some(Vector2::new(x / w, y / w))
+    }
+    pub fn determinant(&self) -> D {
+        let m = &self.m;
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    }
+    /// Transform undoing this one, `None` when it collapses the plane.
+    pub fn inverse(&self) -> Option<Projective2<D, T, F>> {
+        let det = self.determinant();
+        if !det.can_div_safe() {
+            return None;
+        }
+        let m = &self.m;
+        let cofactor = |r0: usize, r1: usize, c0: usize, c1: usize| {
+            (m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0]) / det
+        };
+        Some(Projective2::new([
+            [
+                cofactor(1, 2, 1, 2),
+                cofactor(2, 0, 1, 2),
+                cofactor(0, 1, 1, 2),
+            ],
+            [
+                cofactor(1, 2, 2, 0),
+                cofactor(2, 0, 2, 0),
+                cofactor(0, 1, 2, 0),
+            ],
+            [
+                cofactor(1, 2, 0, 1),
+                cofactor(2, 0, 0, 1),
+                cofactor(0, 1, 0, 1),
+            ],
+        ]))
+    }
+}
+impl<D, F, T, NT> Mul<Projective2<D, F, T>> for Projective2<D, T, NT>
+where
+    D: Decimal,
+    F: Unit,
+    T: Unit,
+    NT: Unit,
+{
+    type Output = Projective2<D, F, NT>;
+
+    fn mul(self, rhs: Projective2<D, F, T>) -> Self::Output {
+        let [x, y, w] = rhs.m;
+        Projective2::new([
+            self.transform_homogeneous(x),
+            self.transform_homogeneous(y),
+            self.transform_homogeneous(w),
+        ])
+    }
+}
+impl<D, F, T, NT> Mul<Affine2<D, F, T>> for Projective2<D, T, NT>
+where
+    D: Decimal,
+    F: Unit,
+    T: Unit,
+    NT: Unit,
+{
+    type Output = Projective2<D, F, NT>;
+
+    fn mul(self, rhs: Affine2<D, F, T>) -> Self::Output {
+        self * Projective2::from(rhs)
+    }
+}
+impl<D, F, T, NT> Mul<Projective2<D, F, T>> for Affine2<D, T, NT>
+where
+    D: Decimal,
+    F: Unit,
+    T: Unit,
+    NT: Unit,
+{
+    type Output = Projective2<D, F, NT>;
+
+    fn mul(self, rhs: Projective2<D, F, T>) -> Self::Output {
+        Projective2::from(self) * rhs
+    }
+}
+impl<D, F, T> From<Affine2<D, F, T>> for Projective2<D, F, T>
+where
+    D: Decimal,
+    F: Unit,
+    T: Unit,
+{
+    fn from(value: Affine2<D, F, T>) -> Self {
+        let (zero, one) = (D::zero(), D::one());
+        Projective2::new([
+            [value.m00, value.m01, zero],
+            [value.m10, value.m11, zero],
+            [value.m20, value.m21, one],
+        ])
+    }
+}
+impl<D, F, T> From<Projective2<D, F, T>> for [[D; 3]; 3]
+where
+    D: Decimal,
+    F: Unit,
+    T: Unit,
+{
+    fn from(value: Projective2<D, F, T>) -> Self {
+        value.m
+    }
+}
@@ -0,0 +1,285 @@
+use std::ops::Mul;
+
+use crate::math::{Angle, Decimal, Radians, Unit, Vector3};
+
+/// Rotation in 3D stored as a unit quaternion.
+///
+/// `a * b` rotates by `b` first, then by `a`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Quaternion<D>
+where
+    D: Decimal,
+{
+    pub x: D,
+    pub y: D,
+    pub z: D,
+    pub w: D,
+}
+impl<D> Default for Quaternion<D>
+where
+    D: Decimal,
+{
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+impl<D> Quaternion<D>
+where
+    D: Decimal,
+{
+    pub fn new(x: D, y: D, z: D, w: D) -> Self {
+        Self { x, y, z, w }
+    }
+    pub fn identity() -> Self {
+        Self::new(D::zero(), D::zero(), D::zero(), D::one())
+    }
+    /// Rotation of `angle` around `axis`, counter clockwise when looking
+    /// against the axis. `None` when the axis is zero.
+    pub fn from_axis_angle<U, A>(axis: Vector3<D, U>, angle: A) -> Option<Self>
+    where
+        U: Unit,
+        A: Angle<D>,
+    {
+        let axis = axis.normalize()?;
+        let two = D::one() + D::one();
+        let (sin, cos) = (angle.radians() / two).sin_cos();
+        Some(Self::new(axis.x * sin, axis.y * sin, axis.z * sin, cos))
+    }
+    /// Rotates around x, then around y, then around z.
+    pub fn from_euler<A>(x: A, y: A, z: A) -> Self
+    where
+        A: Angle<D>,
+    {
+        let axis = |x: D, y: D, z: D, angle: D| {
+            let two = D::one() + D::one();
+            let (sin, cos) = (angle / two).sin_cos();
+            Self::new(x * sin, y * sin, z * sin, cos)
+        };
+        let (zero, one) = (D::zero(), D::one());
+        axis(zero, zero, one, z.radians())
+            * axis(zero, one, zero, y.radians())
+            * axis(one, zero, zero, x.radians())
+    }
+    /// Rotation turning the local z axis towards `forward` and the local y
+    /// axis as close as possible to `up`. `None` when either is zero or they
+    /// are parallel.
+    pub fn look_rotation<U>(forward: Vector3<D, U>, up: Vector3<D, U>) -> Option<Self>
+    where
+        U: Unit,
+    {
+        let forward = forward.normalize()?;
+        let right = up.to_precise().cross(forward).normalize()?;
+        let up = forward.cross(right);
+        Some(Self::from_basis(
+            [right.x, right.y, right.z],
+            [up.x, up.y, up.z],
+            [forward.x, forward.y, forward.z],
+        ))
+    }
+    /// Rotation mapping the x, y and z axes onto the given orthonormal axes.
+    pub(crate) fn from_basis(x: [D; 3], y: [D; 3], z: [D; 3]) -> Self {
+        let one = D::one();
+        let two = one + one;
+        let quarter = one / (two + two);
+        let trace = x[0] + y[1] + z[2];
+        if trace > D::zero() {
+            let s = (trace + one).sqrt() * two;
+            Self::new(
+                (y[2] - z[1]) / s,
+                (z[0] - x[2]) / s,
+                (x[1] - y[0]) / s,
+                quarter * s,
+            )
+        } else if x[0] > y[1] && x[0] > z[2] {
+            let s = (one + x[0] - y[1] - z[2]).sqrt() * two;
+            Self::new(
+                quarter * s,
+                (y[0] + x[1]) / s,
+                (z[0] + x[2]) / s,
+                (y[2] - z[1]) / s,
+            )
+        } else if y[1] > z[2] {
+            let s = (one + y[1] - x[0] - z[2]).sqrt() * two;
+            Self::new(
+                (y[0] + x[1]) / s,
+                quarter * s,
+                (z[1] + y[2]) / s,
+                (z[0] - x[2]) / s,
+            )
+        } else {
+            let s = (one + z[2] - x[0] - y[1]).sqrt() * two;
+            Self::new(
+                (z[0] + x[2]) / s,
+                (z[1] + y[2]) / s,
+                quarter * s,
+                (x[1] - y[0]) / s,
+            )
+        }
+    }
+
+    pub fn dot(&self, other: &Self) -> D {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+    pub fn length(&self) -> D {
+        self.dot(self).sqrt()
+    }
+    /// `None` when the quaternion is zero.
+    pub fn normalize(&self) -> Option<Self> {
+        let length = self.length();
+        if !length.can_div_safe() {
+            return None;
+        }
+        Some(Self::new(
+            self.x / length,
+            self.y / length,
+            self.z / length,
+            self.w / length,
+        ))
+    }
+    pub fn conjugate(&self) -> Self {
+        Self::new(-self.x, -self.y, -self.z, self.w)
+    }
+    /// `None` when the quaternion is zero, equal to the conjugate for unit
+    /// quaternions.
+    pub fn inverse(&self) -> Option<Self> {
+        let length_squared = self.dot(self);
+        if !length_squared.can_div_safe() {
+            return None;
+        }
+        let conjugate = self.conjugate();
+        Some(Self::new(
+            conjugate.x / length_squared,
+            conjugate.y / length_squared,
+            conjugate.z / length_squared,
+            conjugate.w / length_squared,
+        ))
+    }
+
+    /// Axis and angle in `[0, pi]` of the rotation, the x axis for the
+    /// identity.
+    pub fn to_axis_angle(&self) -> (Vector3<D>, Radians<D>) {
+        let q = self.normalize().unwrap_or_default();
+        let q = if q.w < D::zero() {
+            Self::new(-q.x, -q.y, -q.z, -q.w)
+        } else {
+            q
+        };
+        let axis = Vector3::<D>::new(q.x, q.y, q.z);
+        let sin = axis.length();
+        let two = D::one() + D::one();
+        let angle = Radians(two * sin.atan2(q.w));
+        match axis.normalize() {
+            Some(axis) => (axis, angle),
+            None => (
+                Vector3::new(D::one(), D::zero(), D::zero()),
+                Radians(D::zero()),
+            ),
+        }
+    }
+    /// Angles around x, y and z that `from_euler` turns back into this
+    /// rotation, the y angle is in `[-pi/2, pi/2]`.
+    pub fn to_euler(&self) -> (Radians<D>, Radians<D>, Radians<D>) {
+        let [x, y, z] = self.basis();
+        let one = D::one();
+        let sin_y = (-x[2]).clamp(-one, one);
+        let angle_y = sin_y.atan2((one - sin_y * sin_y).sqrt());
+        let (angle_x, angle_z) = if sin_y.abs() < one - D::from_i32(1) / D::from_i32(1_000_000) {
+            (y[2].atan2(z[2]), x[1].atan2(x[0]))
+        } else {
+            // Gimbal lock, the x and z rotations turn around the same axis.
+            (D::zero(), (-y[0]).atan2(y[1]))
+        };
+        (Radians(angle_x), Radians(angle_y), Radians(angle_z))
+    }
+    /// Images of the x, y and z axes.
+    pub(crate) fn basis(&self) -> [[D; 3]; 3] {
+        let q = self.normalize().unwrap_or_default();
+        let one = D::one();
+        let two = one + one;
+        let (x, y, z, w) = (q.x, q.y, q.z, q.w);
+        [
+            [
+                one - two * (y * y + z * z),
+                two * (x * y + w * z),
+                two * (x * z - w * y),
+            ],
+            [
+                two * (x * y - w * z),
+                one - two * (x * x + z * z),
+                two * (y * z + w * x),
+            ],
+            [
+                two * (x * z + w * y),
+                two * (y * z - w * x),
+                one - two * (x * x + y * y),
+            ],
+        ]
+    }
+
+    pub fn rotate<U>(&self, vector: Vector3<D, U>) -> Vector3<D, U>
+    where
+        U: Unit,
+    {
+        let axis = Vector3::new(self.x, self.y, self.z);
+        let two = D::one() + D::one();
+        let t = axis.cross(vector) * two;
+        vector + t * self.w + axis.cross(t)
+    }
+    /// Rotation `t` of the way from `self` to `other`, along the shortest arc.
+    pub fn slerp(&self, other: &Self, t: D) -> Self {
+        let mut dot = self.dot(other);
+        let other = if dot < D::zero() {
+            dot = -dot;
+            Self::new(-other.x, -other.y, -other.z, -other.w)
+        } else {
+            *other
+        };
+        let threshold = D::one() - D::one() / D::from_i32(10_000);
+        let (a, b) = if dot > threshold {
+            // Nearly identical rotations, blending linearly avoids dividing by
+            // a vanishing sine.
+            (D::one() - t, t)
+        } else {
+            let angle = (D::one() - dot * dot).sqrt().atan2(dot);
+            let sin = angle.sin();
+            (
+                ((D::one() - t) * angle).sin() / sin,
+                (t * angle).sin() / sin,
+            )
+        };
+        let blended = Self::new(
+            self.x * a + other.x * b,
+            self.y * a + other.y * b,
+            self.z * a + other.z * b,
+            self.w * a + other.w * b,
+        );
+        blended.normalize().unwrap_or(*self)
+    }
+}
+impl<D> Mul for Quaternion<D>
+where
+    D: Decimal,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+            self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+        )
+    }
+}
+impl<D, U> Mul<Vector3<D, U>> for Quaternion<D>
+where
+    D: Decimal,
+    U: Unit,
+{
+    type Output = Vector3<D, U>;
+
+    fn mul(self, rhs: Vector3<D, U>) -> Self::Output {
+        self.rotate(rhs)
+    }
+}
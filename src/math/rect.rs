@@ -58,6 +58,14 @@ where
         let y = self.min.y <= point.y && self.max.y >= point.y;
         x && y
     }
+    pub fn contains_rect(&self, other: &Rect2<I, U>) -> bool {
+        self.contains_point(&other.min) && self.contains_point(&other.max)
+    }
+    pub fn intersects(&self, other: &Rect2<I, U>) -> bool {
+        let x = self.min.x <= other.max.x && self.max.x >= other.min.x;
+        let y = self.min.y <= other.max.y && self.max.y >= other.min.y;
+        x && y
+    }
     pub fn add_point(&mut self, point: &Vector2<I, U>) {
         self.min.x = self.min.x.min(point.x);
         self.min.y = self.min.y.min(point.y);
@@ -68,6 +76,120 @@ where
         self.add_point(&other.min);
         self.add_point(&other.max);
     }
+    pub fn intersection(&self, other: &Rect2<I, U>) -> Option<Rect2<I, U>> {
+        let rect = Self {
+            min: self.min.max(other.min),
+            max: self.max.min(other.max),
+            _phantom: PhantomData,
+        };
+        (!rect.is_empty()).then_some(rect)
+    }
+    pub fn clamp_point(&self, point: &Vector2<I, U>) -> Vector2<I, U> {
+        point.clamp(self.min, self.max)
+    }
+
+    /// True if the rect contains no point at all, like `Rect2::empty()` does.
+    /// Degenerate rects where `min == max` still contain that single point.
+    pub fn is_empty(&self) -> bool {
+        self.min.x > self.max.x || self.min.y > self.max.y
+    }
+    /// Saturates at `I::max_value()` for integer rects wider than the range
+    /// of `I`, like `width` and `height`. `center` is only exact while the
+    /// size fits.
+    pub fn size(&self) -> Vector2<I, U> {
+        if self.is_empty() {
+            Vector2::zero()
+        } else {
+            Vector2::new(
+                self.max.x.saturating_sub(self.min.x),
+                self.max.y.saturating_sub(self.min.y),
+            )
+        }
+    }
+    pub fn width(&self) -> I {
+        self.size().x
+    }
+    pub fn height(&self) -> I {
+        self.size().y
+    }
+    /// Overflows when the product of the sides doesn't fit in `I`.
+    pub fn area(&self) -> I {
+        let size = self.size();
+        size.x * size.y
+    }
+    pub fn center(&self) -> Vector2<I, U> {
+        let two = I::one() + I::one();
+        self.min + self.size() / two
+    }
+
+    /// Moves the rect by `offset`, saturating at the bounds of `I`.
+    pub fn translate(&self, offset: Vector2<I, U>) -> Self {
+        Self {
+            min: Vector2::new(
+                self.min.x.saturating_add(offset.x),
+                self.min.y.saturating_add(offset.y),
+            ),
+            max: Vector2::new(
+                self.max.x.saturating_add(offset.x),
+                self.max.y.saturating_add(offset.y),
+            ),
+            _phantom: PhantomData,
+        }
+    }
+    /// Grows every side by `amount`, saturating at the bounds of `I`.
+    pub fn inflate(&self, amount: I) -> Self {
+        Self {
+            min: Vector2::new(
+                self.min.x.saturating_sub(amount),
+                self.min.y.saturating_sub(amount),
+            ),
+            max: Vector2::new(
+                self.max.x.saturating_add(amount),
+                self.max.y.saturating_add(amount),
+            ),
+            _phantom: PhantomData,
+        }
+    }
+    /// Shrinks every side by `amount`, axes smaller than twice `amount`
+    /// collapse into their center instead of flipping.
+    pub fn deflate(&self, amount: I) -> Self {
+        let two = I::one() + I::one();
+        let half = self.size() / two;
+        let amount = Vector2::new(amount.min(half.x), amount.min(half.y));
+        Self {
+            min: self.min + amount,
+            max: self.max - amount,
+            _phantom: PhantomData,
+        }
+    }
+    /// Cuts the rect with a vertical line `at` units from its left edge,
+    /// returns the left and right parts.
+    pub fn split_horizontal(&self, at: I) -> (Self, Self) {
+        let x = self.min.x + at.clamp(I::zero(), self.width());
+        let left = Self {
+            max: Vector2::new(x, self.max.y),
+            ..*self
+        };
+        let right = Self {
+            min: Vector2::new(x, self.min.y),
+            ..*self
+        };
+        (left, right)
+    }
+    /// Cuts the rect with a horizontal line `at` units from its top edge,
+    /// returns the top and bottom parts.
+    pub fn split_vertical(&self, at: I) -> (Self, Self) {
+        let y = self.min.y + at.clamp(I::zero(), self.height());
+        let top = Self {
+            max: Vector2::new(self.max.x, y),
+            ..*self
+        };
+        let bottom = Self {
+            min: Vector2::new(self.min.x, y),
+            ..*self
+        };
+        (top, bottom)
+    }
 
     #[inline]
     pub fn min(&self) -> Vector2<I, U> {
@@ -79,6 +201,79 @@ where
     }
 }
 
+// Cell iteration treats the rect as half-open: a cell `p` belongs to the rect
+// when `min <= p < max`, so `size()` is the number of cells along each axis.
+macro_rules! impl_cells_for_ints {
+    ($($t:ty),*) => {
+        $(
+            impl<U> Rect2<$t, U>
+            where
+                U: Unit,
+            {
+                pub fn contains_cell(&self, cell: &Vector2<$t, U>) -> bool {
+                    let x = self.min.x <= cell.x && cell.x < self.max.x;
+                    let y = self.min.y <= cell.y && cell.y < self.max.y;
+                    x && y
+                }
+                /// Every cell, left to right and then top to bottom.
+                pub fn cells(&self) -> impl Iterator<Item = Vector2<$t, U>> + use<U> {
+                    let (min, max) = (self.min, self.max);
+                    (min.y..max.y).flat_map(move |y| (min.x..max.x).map(move |x| Vector2::new(x, y)))
+                }
+                /// Every cell, top to bottom and then left to right.
+                pub fn cells_column_major(&self) -> impl Iterator<Item = Vector2<$t, U>> + use<U> {
+                    let (min, max) = (self.min, self.max);
+                    (min.x..max.x).flat_map(move |x| (min.y..max.y).map(move |y| Vector2::new(x, y)))
+                }
+                /// Cells touching the edges of the rect, clockwise starting at
+                /// `min`. Each cell is yielded once even for thin rects.
+                pub fn border_cells(&self) -> impl Iterator<Item = Vector2<$t, U>> + use<U> {
+                    let (min, max) = (self.min, self.max);
+                    let size = self.size();
+                    let filled = size.x > 0 && size.y > 0;
+                    let top = if filled { min.x..max.x } else { 0..0 };
+                    let right = if filled { min.y + 1..max.y } else { 0..0 };
+                    let bottom = if filled && size.y > 1 { min.x..max.x - 1 } else { 0..0 };
+                    let left = if filled && size.x > 1 { min.y + 1..max.y - 1 } else { 0..0 };
+                    top.map(move |x| Vector2::new(x, min.y))
+                        .chain(right.map(move |y| Vector2::new(max.x - 1, y)))
+                        .chain(bottom.rev().map(move |x| Vector2::new(x, max.y - 1)))
+                        .chain(left.rev().map(move |y| Vector2::new(min.x, y)))
+                }
+                /// Row-major index of `cell` for storage laid out like `cells()`,
+                /// `None` when the index doesn't fit in `usize`.
+                pub fn index_of(&self, cell: &Vector2<$t, U>) -> Option<usize> {
+                    if !self.contains_cell(cell) {
+                        return None;
+                    }
+                    let width = usize::try_from(self.max.x as i128 - self.min.x as i128).ok()?;
+                    let x = usize::try_from(cell.x as i128 - self.min.x as i128).ok()?;
+                    let y = usize::try_from(cell.y as i128 - self.min.y as i128).ok()?;
+                    y.checked_mul(width)?.checked_add(x)
+                }
+                /// Inverse of `index_of`.
+                pub fn point_of(&self, index: usize) -> Option<Vector2<$t, U>> {
+                    if self.is_empty() {
+                        return None;
+                    }
+                    // Offsets are computed in `i128`, the size may not fit in `$t`.
+                    let width = self.max.x as i128 - self.min.x as i128;
+                    let height = self.max.y as i128 - self.min.y as i128;
+                    let (index, width) = (index as u128, u128::try_from(width).ok()?);
+                    if width == 0 || index / width >= u128::try_from(height).ok()? {
+                        return None;
+                    }
+                    Some(Vector2::new(
+                        (self.min.x as i128 + (index % width) as i128) as $t,
+                        (self.min.y as i128 + (index / width) as i128) as $t,
+                    ))
+                }
+            }
+        )*
+    };
+}
+impl_cells_for_ints!(u8, i8, u16, i16, u32, i32, u64, i64);
+
 impl<I, U> From<Rect2<I, U>> for [I; 4]
 where
     I: Integer,
@@ -136,3 +331,115 @@ where
         ]
     }
 }
+
+/// Per edge values, used for margins, paddings and borders. `top` refers to
+/// the `min.y` side of a rect.
+#[derive(Debug, Default, Clone, Copy, CwArithmetic, CwBitops, BcArithmetic, BcBitops)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct EdgeInsets<I>
+where
+    I: Integer,
+{
+    pub top: I,
+    pub right: I,
+    pub bottom: I,
+    pub left: I,
+}
+impl<I> EdgeInsets<I>
+where
+    I: Integer,
+{
+    pub fn new(top: I, right: I, bottom: I, left: I) -> Self {
+        Self {
+            top,
+            right,
+            bottom,
+            left,
+        }
+    }
+    pub fn splat(value: I) -> Self {
+        Self::new(value, value, value, value)
+    }
+    pub fn symmetric(horizontal: I, vertical: I) -> Self {
+        Self::new(vertical, horizontal, vertical, horizontal)
+    }
+
+    /// Sum of the left and right insets.
+    pub fn horizontal(&self) -> I {
+        self.left + self.right
+    }
+    /// Sum of the top and bottom insets.
+    pub fn vertical(&self) -> I {
+        self.top + self.bottom
+    }
+    pub fn total<U: Unit>(&self) -> Vector2<I, U> {
+        Vector2::new(self.horizontal(), self.vertical())
+    }
+
+    pub fn clamp_components(&mut self, min: I, max: I) {
+        self.top = self.top.clamp(min, max);
+        self.right = self.right.clamp(min, max);
+        self.bottom = self.bottom.clamp(min, max);
+        self.left = self.left.clamp(min, max);
+    }
+}
+
+impl<I> From<EdgeInsets<I>> for [I; 4]
+where
+    I: Integer,
+{
+    fn from(value: EdgeInsets<I>) -> Self {
+        [value.top, value.right, value.bottom, value.left]
+    }
+}
+
+impl<I, U> Rect2<I, U>
+where
+    I: Integer,
+    U: Unit,
+{
+    /// Moves every edge inwards by its inset, axes where the insets add up to
+    /// more than the size collapse into the middle of the remaining space.
+    pub fn inset(&self, insets: &EdgeInsets<I>) -> Self {
+        let two = I::one() + I::one();
+        // Clamping every inset to the size keeps both edges inside the rect,
+        // so unsigned coordinates can't wrap.
+        let size = self.size();
+        let mut min = Vector2::new(
+            self.min.x + insets.left.min(size.x),
+            self.min.y + insets.top.min(size.y),
+        );
+        let mut max = Vector2::new(
+            self.max.x - insets.right.min(size.x),
+            self.max.y - insets.bottom.min(size.y),
+        );
+        if min.x > max.x {
+            min.x = max.x + (min.x - max.x) / two;
+            max.x = min.x;
+        }
+        if min.y > max.y {
+            min.y = max.y + (min.y - max.y) / two;
+            max.y = min.y;
+        }
+        Self {
+            min,
+            max,
+            _phantom: PhantomData,
+        }
+    }
+    /// Moves every edge outwards by its inset, saturating at the bounds of
+    /// `I`.
+    pub fn outset(&self, insets: &EdgeInsets<I>) -> Self {
+        Self {
+            min: Vector2::new(
+                self.min.x.saturating_sub(insets.left),
+                self.min.y.saturating_sub(insets.top),
+            ),
+            max: Vector2::new(
+                self.max.x.saturating_add(insets.right),
+                self.max.y.saturating_add(insets.bottom),
+            ),
+            _phantom: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use std::ops::Mul;
+
+use crate::math::{Affine2, Angle, Decimal, Radians, Unit, Vector2};
+
+/// Rotation stored as the unit complex number `cos + i sin`, rotating points
+/// and composing rotations only takes multiplications.
+///
+/// Composition renormalizes cheaply so chains of products don't drift away
+/// from unit length, `renormalize` does it exactly.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Rotation2<D>
+where
+    D: Decimal,
+{
+    cos: D,
+    sin: D,
+}
+impl<D> Default for Rotation2<D>
+where
+    D: Decimal,
+{
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+impl<D> Rotation2<D>
+where
+    D: Decimal,
+{
+    pub fn identity() -> Self {
+        Self {
+            cos: D::one(),
+            sin: D::zero(),
+        }
+    }
+    pub fn new<A>(angle: A) -> Self
+    where
+        A: Angle<D>,
+    {
+        let (sin, cos) = angle.radians().sin_cos();
+        Self { cos, sin }
+    }
+    /// Rotation turning the x axis towards `direction`, `None` when it is
+    /// zero.
+    pub fn from_direction<U>(direction: Vector2<D, U>) -> Option<Self>
+    where
+        U: Unit,
+    {
+        Self::from_cos_sin(direction.x, direction.y)
+    }
+    /// Normalizes `(cos, sin)`, `None` when both are zero.
+    pub fn from_cos_sin(cos: D, sin: D) -> Option<Self> {
+        let length = (cos * cos + sin * sin).sqrt();
+        if !length.can_div_safe() {
+            return None;
+        }
+        Some(Self {
+            cos: cos / length,
+            sin: sin / length,
+        })
+    }
+    /// Rotation turning `from` towards `to`, `None` when either is zero.
+    pub fn between<U>(from: Vector2<D, U>, to: Vector2<D, U>) -> Option<Self>
+    where
+        U: Unit,
+    {
+        Self::from_cos_sin(from.x * to.x + from.y * to.y, from.x * to.y - from.y * to.x)
+    }
+
+    #[inline]
+    pub fn cos(&self) -> D {
+        self.cos
+    }
+    #[inline]
+    pub fn sin(&self) -> D {
+        self.sin
+    }
+    /// Angle in `(-pi, pi]`.
+    pub fn angle(&self) -> Radians<D> {
+        Radians(self.sin.atan2(self.cos))
+    }
+    pub fn inverse(&self) -> Self {
+        Self {
+            cos: self.cos,
+            sin: -self.sin,
+        }
+    }
+    pub fn rotate<U>(&self, vector: Vector2<D, U>) -> Vector2<D, U>
+    where
+        U: Unit,
+    {
+        Vector2::new(
+            vector.x * self.cos - vector.y * self.sin,
+            vector.x * self.sin + vector.y * self.cos,
+        )
+    }
+    /// Rotation `t` of the way from `self` to `other`, along the shortest arc.
+    pub fn slerp(&self, other: &Self, t: D) -> Self {
+        let delta = self.inverse() * *other;
+        *self * Self::new(Radians(delta.angle().0 * t))
+    }
+    /// Brings the complex number back to unit length.
+    pub fn renormalize(&mut self) {
+        *self = Self::from_cos_sin(self.cos, self.sin).unwrap_or_default();
+    }
+    pub fn to_affine<F, T>(&self) -> Affine2<D, F, T>
+    where
+        F: Unit,
+        T: Unit,
+    {
+        Affine2::new(
+            self.cos,
+            self.sin,
+            -self.sin,
+            self.cos,
+            D::zero(),
+            D::zero(),
+        )
+    }
+}
+impl<D> Mul for Rotation2<D>
+where
+    D: Decimal,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let cos = self.cos * rhs.cos - self.sin * rhs.sin;
+        let sin = self.sin * rhs.cos + self.cos * rhs.sin;
+        // First order correction towards unit length, exact enough for the
+        // error a single product adds.
+        let two = D::one() + D::one();
+        let three = two + D::one();
+        let correction = (three - (cos * cos + sin * sin)) / two;
+        Self {
+            cos: cos * correction,
+            sin: sin * correction,
+        }
+    }
+}
+impl<D, U> Mul<Vector2<D, U>> for Rotation2<D>
+where
+    D: Decimal,
+    U: Unit,
+{
+    type Output = Vector2<D, U>;
+
+    fn mul(self, rhs: Vector2<D, U>) -> Self::Output {
+        self.rotate(rhs)
+    }
+}
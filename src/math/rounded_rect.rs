@@ -0,0 +1,223 @@
+use crate::math::{
+    Angle, CornerData, Decimal, Line2, Radians, Rect2, Unit, Vector, Vector2, bezier::CubicBezier,
+};
+
+/// Rect with a radius per corner, `top` refers to the `min.y` side of the
+/// rect as in screen space.
+///
+/// Radii are clamped on construction so they are never negative and adjacent
+/// corners never overlap, when they would all of them are scaled down by the
+/// same factor.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RoundedRect2<D, U = ()>
+where
+    D: Decimal,
+    U: Unit,
+{
+    rect: Rect2<D, U>,
+    radii: CornerData<D>,
+}
+impl<D, U> RoundedRect2<D, U>
+where
+    D: Decimal,
+    U: Unit,
+{
+    pub fn new(rect: Rect2<D, U>, mut radii: CornerData<D>) -> Self {
+        radii.clamp_components(D::zero(), D::max_value());
+        let size = rect.size();
+        let mut scale = D::one();
+        for (length, sum) in [
+            (size.x, radii.top_left + radii.top_right),
+            (size.x, radii.bottom_left + radii.bottom_right),
+            (size.y, radii.top_left + radii.bottom_left),
+            (size.y, radii.top_right + radii.bottom_right),
+        ] {
+            if sum > length {
+                scale = scale.min(length / sum);
+            }
+        }
+        Self {
+            rect,
+            radii: radii * scale,
+        }
+    }
+    pub fn uniform(rect: Rect2<D, U>, radius: D) -> Self {
+        Self::new(rect, CornerData::splat(radius))
+    }
+
+    #[inline]
+    pub fn rect(&self) -> Rect2<D, U> {
+        self.rect
+    }
+    #[inline]
+    pub fn radii(&self) -> CornerData<D> {
+        self.radii
+    }
+
+    /// Signed distance from `point` to the outline, negative inside.
+    pub fn signed_distance(&self, point: &Vector2<D, U>) -> D {
+        let two = D::one() + D::one();
+        let center = self.rect.center();
+        let half = self.rect.size() / two;
+        let radius = match (point.x > center.x, point.y > center.y) {
+            (false, false) => self.radii.top_left,
+            (true, false) => self.radii.top_right,
+            (false, true) => self.radii.bottom_left,
+            (true, true) => self.radii.bottom_right,
+        };
+        let offset = *point - center;
+        let q: Vector2<D, U> = Vector2::new(
+            offset.x.abs() - half.x + radius,
+            offset.y.abs() - half.y + radius,
+        );
+        let outside = q.max(Vector2::zero()).length();
+        let inside = q.x.max(q.y).min(D::zero());
+        outside + inside - radius
+    }
+    pub fn contains_point(&self, point: &Vector2<D, U>) -> bool {
+        self.signed_distance(point) <= D::zero()
+    }
+
+    /// Closed outline going clockwise (in a y-down space) from the end of the
+    /// top-left corner. Corners with a zero radius produce no arc.
+    pub fn outline(&self) -> Vec<PathSegment<D, U>> {
+        let pi = D::pi();
+        let half_pi = pi / (D::one() + D::one());
+        let (min, max) = (self.rect.min(), self.rect.max());
+        let r = self.radii;
+        let corners = [
+            (
+                Vector2::new(max.x - r.top_right, min.y),
+                Vector2::new(max.x - r.top_right, min.y + r.top_right),
+                r.top_right,
+                -half_pi,
+                Vector2::new(max.x, min.y + r.top_right),
+            ),
+            (
+                Vector2::new(max.x, max.y - r.bottom_right),
+                Vector2::new(max.x - r.bottom_right, max.y - r.bottom_right),
+                r.bottom_right,
+                D::zero(),
+                Vector2::new(max.x - r.bottom_right, max.y),
+            ),
+            (
+                Vector2::new(min.x + r.bottom_left, max.y),
+                Vector2::new(min.x + r.bottom_left, max.y - r.bottom_left),
+                r.bottom_left,
+                half_pi,
+                Vector2::new(min.x, max.y - r.bottom_left),
+            ),
+            (
+                Vector2::new(min.x, min.y + r.top_left),
+                Vector2::new(min.x + r.top_left, min.y + r.top_left),
+                r.top_left,
+                pi,
+                Vector2::new(min.x + r.top_left, min.y),
+            ),
+        ];
+
+        let mut path = Vec::with_capacity(8);
+        let mut cursor = corners[3].4;
+        for (arc_start, center, radius, start, arc_end) in corners {
+            if arc_start.x != cursor.x || arc_start.y != cursor.y {
+                path.push(PathSegment::Line(Line2::new(cursor, arc_start)));
+            }
+            if radius > D::zero() {
+                path.push(PathSegment::Arc(CornerArc {
+                    center,
+                    radius,
+                    start: Radians(start),
+                    sweep: Radians(half_pi),
+                }));
+            }
+            cursor = arc_end;
+        }
+        path
+    }
+    /// Same as `outline` with every segment converted to a cubic Bezier.
+    pub fn to_cubics(&self) -> Vec<CubicBezier<D, U>> {
+        self.outline()
+            .into_iter()
+            .map(|segment| segment.to_cubic())
+            .collect()
+    }
+}
+
+/// Circular arc starting at angle `start` and going `sweep` radians in the
+/// direction of increasing angles.
+#[derive(Debug, Clone, Copy)]
+pub struct CornerArc<D, U = ()>
+where
+    D: Decimal,
+    U: Unit,
+{
+    pub center: Vector2<D, U>,
+    pub radius: D,
+    pub start: Radians<D>,
+    pub sweep: Radians<D>,
+}
+impl<D, U> CornerArc<D, U>
+where
+    D: Decimal,
+    U: Unit,
+{
+    pub fn point_at<A>(&self, angle: A) -> Vector2<D, U>
+    where
+        A: Angle<D>,
+    {
+        let (sin, cos) = angle.radians().sin_cos();
+        self.center + Vector2::new(cos, sin) * self.radius
+    }
+    /// Single cubic approximating the arc, accurate for sweeps up to a
+    /// quarter turn.
+    pub fn to_cubic(&self) -> CubicBezier<D, U> {
+        let four = D::one() + D::one() + D::one() + D::one();
+        let three = D::one() + D::one() + D::one();
+        let (start, sweep) = (self.start.radians(), self.sweep.radians());
+        let end = start + sweep;
+        let (sin, cos) = (sweep / four).sin_cos();
+        let k = four / three * (sin / cos) * self.radius;
+
+        let (start_sin, start_cos) = start.sin_cos();
+        let (end_sin, end_cos) = end.sin_cos();
+        let p0 = self.point_at(Radians(start));
+        let p3 = self.point_at(Radians(end));
+        CubicBezier {
+            p0,
+            p1: p0 + Vector2::new(-start_sin, start_cos) * k,
+            p2: p3 - Vector2::new(-end_sin, end_cos) * k,
+            p3,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum PathSegment<D, U = ()>
+where
+    D: Decimal,
+    U: Unit,
+{
+    Line(Line2<D, U>),
+    Arc(CornerArc<D, U>),
+}
+impl<D, U> PathSegment<D, U>
+where
+    D: Decimal,
+    U: Unit,
+{
+    pub fn to_cubic(&self) -> CubicBezier<D, U> {
+        match self {
+            PathSegment::Line(line) => {
+                let three = D::one() + D::one() + D::one();
+                let (start, end) = (line.start(), line.end());
+                CubicBezier {
+                    p0: start,
+                    p1: start.lerp(end, D::one() / three),
+                    p2: start.lerp(end, (D::one() + D::one()) / three),
+                    p3: end,
+                }
+            }
+            PathSegment::Arc(arc) => arc.to_cubic(),
+        }
+    }
+}
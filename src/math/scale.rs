@@ -0,0 +1,124 @@
+use std::{marker::PhantomData, ops::Mul};
+
+use crate::math::{Affine2, Decimal, LogicalSpace, PhysicalSpace, Rect2, Unit, Vector2};
+
+/// Scale from logical to physical pixels, the DPI scale of a display.
+pub type DevicePixelRatio<D> = ScaleFactor<D, LogicalSpace, PhysicalSpace>;
+
+/// Uniform scale converting values from space `F` into space `T`.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ScaleFactor<D, F = (), T = ()>
+where
+    D: Decimal,
+    F: Unit,
+    T: Unit,
+{
+    pub factor: D,
+    _phantom: PhantomData<(F, T)>,
+}
+impl<D, F, T> ScaleFactor<D, F, T>
+where
+    D: Decimal,
+    F: Unit,
+    T: Unit,
+{
+    pub fn new(factor: D) -> Self {
+        Self {
+            factor,
+            _phantom: PhantomData,
+        }
+    }
+    pub fn inverse(&self) -> ScaleFactor<D, T, F> {
+        ScaleFactor::new(D::one() / self.factor)
+    }
+
+    pub fn transform_vector(&self, vector: Vector2<D, F>) -> Vector2<D, T> {
+        Vector2::new(vector.x * self.factor, vector.y * self.factor)
+    }
+    pub fn transform_rect(&self, rect: &Rect2<D, F>) -> Rect2<D, T> {
+        Rect2::new(
+            self.transform_vector(rect.min()),
+            self.transform_vector(rect.max()),
+        )
+    }
+    /// Appends the scale to `affine` so it outputs into `T` instead of `F`.
+    pub fn transform_affine<S>(&self, affine: Affine2<D, S, F>) -> Affine2<D, S, T>
+    where
+        S: Unit,
+    {
+        self.to_affine() * affine
+    }
+    pub fn to_affine(&self) -> Affine2<D, F, T> {
+        Affine2::from_scale(self.factor)
+    }
+}
+impl<D, F, T, NT> Mul<ScaleFactor<D, F, T>> for ScaleFactor<D, T, NT>
+where
+    D: Decimal,
+    F: Unit,
+    T: Unit,
+    NT: Unit,
+{
+    type Output = ScaleFactor<D, F, NT>;
+
+    fn mul(self, rhs: ScaleFactor<D, F, T>) -> Self::Output {
+        ScaleFactor::new(self.factor * rhs.factor)
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum RoundingPolicy {
+    /// Rounds every edge to the nearest pixel, rects sharing an edge keep
+    /// sharing it.
+    #[default]
+    Round,
+    Floor,
+    Ceil,
+    /// Grows the rect to the pixels it touches.
+    Outward,
+    /// Shrinks the rect to the pixels it fully covers.
+    Inward,
+}
+impl RoundingPolicy {
+    fn snap_min<D>(self, value: D) -> i32
+    where
+        D: Decimal,
+    {
+        match self {
+            Self::Round => value.round(),
+            Self::Floor | Self::Outward => value.floor(),
+            Self::Ceil | Self::Inward => value.ceil(),
+        }
+        .to_i32()
+    }
+    fn snap_max<D>(self, value: D) -> i32
+    where
+        D: Decimal,
+    {
+        match self {
+            Self::Round => value.round(),
+            Self::Floor | Self::Inward => value.floor(),
+            Self::Ceil | Self::Outward => value.ceil(),
+        }
+        .to_i32()
+    }
+}
+
+impl<D> Rect2<D, PhysicalSpace>
+where
+    D: Decimal,
+{
+    /// Snaps the edges to whole pixels.
+    pub fn snap(&self, policy: RoundingPolicy) -> Rect2<i32, PhysicalSpace> {
+        let (min, max) = (self.min(), self.max());
+        let min = Vector2::new(policy.snap_min(min.x), policy.snap_min(min.y));
+        // Inward snapping of rects thinner than a pixel would flip them.
+        let max = Vector2::new(
+            policy.snap_max(max.x).max(min.x),
+            policy.snap_max(max.y).max(min.y),
+        );
+        Rect2::new(min, max)
+    }
+}
@@ -1,6 +1,23 @@
 use std::{marker::PhantomData, ops::Mul};
 
-use crate::math::{Angle, Decimal, Unit, Vector2, ViewSpace, WorldSpace};
+use crate::math::{Angle, Decimal, Radians, Unit, Vector2, ViewSpace, WorldSpace};
+
+/// Parts an `Affine2` is made of, applied as scale, then skew, then rotation,
+/// then translation.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct AffineParts<D, T = ()>
+where
+    D: Decimal,
+    T: Unit,
+{
+    pub translation: Vector2<D, T>,
+    pub rotation: Radians<D>,
+    /// Scale along the local axes, a reflection shows up as a negative y.
+    pub scale: Vector2<D>,
+    /// Angle the local y axis is leaned by towards x.
+    pub skew: Radians<D>,
+}
 
 #[derive(Debug, Clone, Copy)]
 #[rustfmt::skip]
@@ -65,6 +82,204 @@ where
             _phantom: PhantomData,
         }
     }
+    pub fn identity() -> Self {
+        Self::from_scale(D::one())
+    }
+    /// Scales, then rotates, then translates.
+    pub fn from_scale_rotation_translation<A>(
+        scale: Vector2<D, T>,
+        rotation: A,
+        translation: Vector2<D, T>,
+    ) -> Self
+    where
+        A: Angle<D>,
+    {
+        Self::from_nonuniform_scale(scale)
+            .rotate(rotation)
+            .translate(translation)
+    }
+    /// Rotation leaving `pivot` in place.
+    pub fn from_rotation_about<A>(pivot: Vector2<D, F>, rotation: A) -> Self
+    where
+        A: Angle<D>,
+    {
+        Self::from_rotation(rotation).about(pivot)
+    }
+    /// Scale leaving `pivot` in place.
+    pub fn from_scale_about(pivot: Vector2<D, F>, scale: Vector2<D, T>) -> Self {
+        Self::from_nonuniform_scale(scale).about(pivot)
+    }
+    /// Shear moving x by `shear.x` per unit of y and y by `shear.y` per unit
+    /// of x.
+    #[rustfmt::skip]
+    pub fn from_shear(shear: Vector2<D>) -> Self {
+        Self {
+            m00: D::one(), m01: shear.y,
+            m10: shear.x, m11: D::one(),
+            m20: D::zero(), m21: D::zero(),
+            _phantom: PhantomData,
+        }
+    }
+    /// Mirror across the line through `point` along `direction`, `None` when
+    /// the direction is zero.
+    pub fn from_reflection(point: Vector2<D, F>, direction: Vector2<D, F>) -> Option<Self> {
+        let length = (direction.x * direction.x + direction.y * direction.y).sqrt();
+        if !length.can_div_safe() {
+            return None;
+        }
+        let (x, y) = (direction.x / length, direction.y / length);
+        let two = D::one() + D::one();
+        let cross = two * x * y;
+        Some(
+            Self::new(
+                two * x * x - D::one(),
+                cross,
+                cross,
+                two * y * y - D::one(),
+                D::zero(),
+                D::zero(),
+            )
+            .about(point),
+        )
+    }
+    /// Places the local origin at `position` with the local x axis pointing
+    /// at `target`.
+    pub fn look_at(position: Vector2<D, T>, target: Vector2<D, T>) -> Self {
+        let direction = target - position;
+        let angle = if direction.x == D::zero() && direction.y == D::zero() {
+            D::zero()
+        } else {
+            direction.y.atan2(direction.x)
+        };
+        Self::from_rotation(Radians(angle)).translate(position)
+    }
+
+    /// Follows the transform by a translation.
+    pub fn translate(self, offset: Vector2<D, T>) -> Self {
+        Affine2::<D, T, T>::from_translation(offset) * self
+    }
+    /// Follows the transform by a rotation around the origin.
+    pub fn rotate<A>(self, rotation: A) -> Self
+    where
+        A: Angle<D>,
+    {
+        Affine2::<D, T, T>::from_rotation(rotation) * self
+    }
+    /// Follows the transform by a uniform scale from the origin.
+    pub fn scale(self, scale: D) -> Self {
+        Affine2::<D, T, T>::from_scale(scale) * self
+    }
+    /// Follows the transform by a scale from the origin.
+    pub fn scale_nonuniform(self, scale: Vector2<D, T>) -> Self {
+        Affine2::<D, T, T>::from_nonuniform_scale(scale) * self
+    }
+    /// Follows the transform by a shear.
+    pub fn shear(self, shear: Vector2<D>) -> Self {
+        Affine2::<D, T, T>::from_shear(shear) * self
+    }
+    /// Follows the transform by `next`, moving the output into its space.
+    pub fn then<NT>(self, next: Affine2<D, T, NT>) -> Affine2<D, F, NT>
+    where
+        NT: Unit,
+    {
+        next * self
+    }
+
+    /// Moves the fixed point of the linear part from the origin to `pivot`.
+    fn about(mut self, pivot: Vector2<D, F>) -> Self {
+        self.m20 = pivot.x - (pivot.x * self.m00 + pivot.y * self.m10);
+        self.m21 = pivot.y - (pivot.x * self.m01 + pivot.y * self.m11);
+        self
+    }
+
+    pub fn transform_point(&self, point: Vector2<D, F>) -> Vector2<D, T> {
+        Vector2::new(
+            point.x * self.m00 + point.y * self.m10 + self.m20,
+            point.x * self.m01 + point.y * self.m11 + self.m21,
+        )
+    }
+    /// Transforms a direction, ignoring the translation.
+    pub fn transform_vector(&self, vector: Vector2<D, F>) -> Vector2<D, T> {
+        Vector2::new(
+            vector.x * self.m00 + vector.y * self.m10,
+            vector.x * self.m01 + vector.y * self.m11,
+        )
+    }
+    pub fn determinant(&self) -> D {
+        self.m00 * self.m11 - self.m01 * self.m10
+    }
+    /// Transform undoing this one, `None` when it collapses the plane.
+    pub fn inverse(&self) -> Option<Affine2<D, T, F>> {
+        let det = self.determinant();
+        if !det.can_div_safe() {
+            return None;
+        }
+        let i00 = self.m11 / det;
+        let i01 = -self.m01 / det;
+        let i10 = -self.m10 / det;
+        let i11 = self.m00 / det;
+        Some(Affine2::new(
+            i00,
+            i01,
+            i10,
+            i11,
+            -(self.m20 * i00 + self.m21 * i10),
+            -(self.m20 * i01 + self.m21 * i11),
+        ))
+    }
+
+    #[rustfmt::skip]
+    pub fn from_parts(parts: &AffineParts<D, T>) -> Self {
+        let (sin, cos) = parts.rotation.0.sin_cos();
+        let (skew_sin, skew_cos) = parts.skew.0.sin_cos();
+        let shear = if skew_cos.can_div_safe() { skew_sin / skew_cos } else { D::zero() };
+        let (sx, sy) = (parts.scale.x, parts.scale.y);
+        Self {
+            m00: sx * cos, m01: sx * sin,
+            m10: sy * (cos * shear - sin), m11: sy * (sin * shear + cos),
+            m20: parts.translation.x, m21: parts.translation.y,
+            _phantom: PhantomData,
+        }
+    }
+    /// Splits the transform into parts that `from_parts` turns back into it.
+    pub fn decompose(&self) -> AffineParts<D, T> {
+        let sx = (self.m00 * self.m00 + self.m01 * self.m01).sqrt();
+        let rotation = if sx.can_div_safe() {
+            self.m01.atan2(self.m00)
+        } else {
+            D::zero()
+        };
+        let (sin, cos) = rotation.sin_cos();
+        let sy = cos * self.m11 - sin * self.m10;
+        let shear = if sy.can_div_safe() {
+            (cos * self.m10 + sin * self.m11) / sy
+        } else {
+            D::zero()
+        };
+        AffineParts {
+            translation: Vector2::new(self.m20, self.m21),
+            rotation: Radians(rotation),
+            scale: Vector2::new(sx, sy),
+            skew: Radians(shear.atan2(D::one())),
+        }
+    }
+    /// Blends the decomposed parts of `a` and `b`, the rotation takes the
+    /// shortest way around.
+    pub fn interpolate(a: &Self, b: &Self, t: D) -> Self {
+        let (a, b) = (a.decompose(), b.decompose());
+        let lerp = |a: D, b: D| a + (b - a) * t;
+        let mut turn = b.rotation.0 - a.rotation.0;
+        turn -= D::tau() * (turn / D::tau()).round();
+        Self::from_parts(&AffineParts {
+            translation: Vector2::new(
+                lerp(a.translation.x, b.translation.x),
+                lerp(a.translation.y, b.translation.y),
+            ),
+            rotation: Radians(a.rotation.0 + turn * t),
+            scale: Vector2::new(lerp(a.scale.x, b.scale.x), lerp(a.scale.y, b.scale.y)),
+            skew: Radians(lerp(a.skew.0, b.skew.0)),
+        })
+    }
 }
 impl<D> Affine2<D, WorldSpace, ViewSpace>
 where
@@ -85,8 +300,8 @@ where
         let m01 = sin / zoom;
         let m10 = -sin / zoom;
         let m11 = cos / zoom;
-        let m20 = -(m00 * position.x + m01 * position.y);
-        let m21 = -(m10 * position.x + m11 * position.y);
+        let m20 = -(m00 * position.x + m10 * position.y);
+        let m21 = -(m01 * position.x + m11 * position.y);
 
         Affine2 {
             m00,
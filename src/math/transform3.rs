@@ -0,0 +1,181 @@
+use std::{marker::PhantomData, ops::Mul};
+
+use crate::math::{Angle, Decimal, Quaternion, Unit, Vector3};
+
+/// Affine transform in 3D, using the same row vector layout as `Affine2`: the
+/// first three rows are the images of the x, y and z axes and the last one is
+/// the translation.
+#[derive(Debug, Clone, Copy)]
+pub struct Affine3<D, F = (), T = ()>
+where
+    D: Decimal,
+    F: Unit,
+    T: Unit,
+{
+    pub m: [[D; 3]; 4],
+    _phantom: PhantomData<(F, T)>,
+}
+impl<D, F, T> Affine3<D, F, T>
+where
+    D: Decimal,
+    F: Unit,
+    T: Unit,
+{
+    pub fn new(m: [[D; 3]; 4]) -> Self {
+        Self {
+            m,
+            _phantom: PhantomData,
+        }
+    }
+    pub fn identity() -> Self {
+        Self::from_scale(D::one())
+    }
+    pub fn from_translation(translation: Vector3<D, T>) -> Self {
+        let mut affine = Self::identity();
+        affine.m[3] = [translation.x, translation.y, translation.z];
+        affine
+    }
+    pub fn from_rotation(rotation: Quaternion<D>) -> Self {
+        let [x, y, z] = rotation.basis();
+        let zero = D::zero();
+        Self::new([x, y, z, [zero, zero, zero]])
+    }
+    /// Rotation of `angle` around `axis`, `None` when the axis is zero.
+    pub fn from_axis_angle<U, A>(axis: Vector3<D, U>, angle: A) -> Option<Self>
+    where
+        U: Unit,
+        A: Angle<D>,
+    {
+        Quaternion::from_axis_angle(axis, angle).map(Self::from_rotation)
+    }
+    pub fn from_scale(scale: D) -> Self {
+        Self::from_nonuniform_scale(Vector3::splat(scale))
+    }
+    pub fn from_nonuniform_scale(scale: Vector3<D, T>) -> Self {
+        let zero = D::zero();
+        Self::new([
+            [scale.x, zero, zero],
+            [zero, scale.y, zero],
+            [zero, zero, scale.z],
+            [zero, zero, zero],
+        ])
+    }
+    /// Scales, then rotates, then translates.
+    pub fn from_scale_rotation_translation(
+        scale: Vector3<D, T>,
+        rotation: Quaternion<D>,
+        translation: Vector3<D, T>,
+    ) -> Self {
+        let mut affine = Self::from_rotation(rotation);
+        for (row, scale) in affine.m.iter_mut().zip([scale.x, scale.y, scale.z]) {
+            for value in row.iter_mut() {
+                *value *= scale;
+            }
+        }
+        affine.m[3] = [translation.x, translation.y, translation.z];
+        affine
+    }
+    /// Places the local origin at `position` with the local z axis pointing
+    /// at `target` and the local y axis as close as possible to `up`. The
+    /// inverse is the matching view transform. `None` when the directions are
+    /// zero or parallel.
+    pub fn look_at(
+        position: Vector3<D, T>,
+        target: Vector3<D, T>,
+        up: Vector3<D, T>,
+    ) -> Option<Self> {
+        let rotation = Quaternion::look_rotation(target - position, up)?;
+        Some(Self::from_scale_rotation_translation(
+            Vector3::one(),
+            rotation,
+            position,
+        ))
+    }
+
+    pub fn transform_point(&self, point: Vector3<D, F>) -> Vector3<D, T> {
+        let [x, y, z] = self.transform_array([point.x, point.y, point.z]);
+        Vector3::new(x + self.m[3][0], y + self.m[3][1], z + self.m[3][2])
+    }
+    /// Transforms a direction, ignoring the translation.
+    pub fn transform_vector(&self, vector: Vector3<D, F>) -> Vector3<D, T> {
+        let [x, y, z] = self.transform_array([vector.x, vector.y, vector.z]);
+        Vector3::new(x, y, z)
+    }
+    pub fn determinant(&self) -> D {
+        let m = &self.m;
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    }
+    /// Transform undoing this one, `None` when it collapses space.
+    pub fn inverse(&self) -> Option<Affine3<D, T, F>> {
+        let det = self.determinant();
+        if !det.can_div_safe() {
+            return None;
+        }
+        let m = &self.m;
+        let cofactor = |r0: usize, r1: usize, c0: usize, c1: usize| {
+            (m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0]) / det
+        };
+        let linear = [
+            [
+                cofactor(1, 2, 1, 2),
+                cofactor(2, 0, 1, 2),
+                cofactor(0, 1, 1, 2),
+            ],
+            [
+                cofactor(1, 2, 2, 0),
+                cofactor(2, 0, 2, 0),
+                cofactor(0, 1, 2, 0),
+            ],
+            [
+                cofactor(1, 2, 0, 1),
+                cofactor(2, 0, 0, 1),
+                cofactor(0, 1, 0, 1),
+            ],
+        ];
+        let mut inverse = Affine3::new([linear[0], linear[1], linear[2], [D::zero(); 3]]);
+        let [x, y, z] = inverse.transform_array(m[3]);
+        inverse.m[3] = [-x, -y, -z];
+        Some(inverse)
+    }
+
+    fn transform_array(&self, [x, y, z]: [D; 3]) -> [D; 3] {
+        let m = &self.m;
+        [
+            x * m[0][0] + y * m[1][0] + z * m[2][0],
+            x * m[0][1] + y * m[1][1] + z * m[2][1],
+            x * m[0][2] + y * m[1][2] + z * m[2][2],
+        ]
+    }
+}
+impl<D, F, T, NT> Mul<Affine3<D, F, T>> for Affine3<D, T, NT>
+where
+    D: Decimal,
+    F: Unit,
+    T: Unit,
+    NT: Unit,
+{
+    type Output = Affine3<D, F, NT>;
+
+    fn mul(self, rhs: Affine3<D, F, T>) -> Self::Output {
+        let [x, y, z, t] = rhs.m;
+        let t = self.transform_point(Vector3::new(t[0], t[1], t[2]));
+        Affine3::new([
+            self.transform_array(x),
+            self.transform_array(y),
+            self.transform_array(z),
+            [t.x, t.y, t.z],
+        ])
+    }
+}
+impl<D, F, T> From<Affine3<D, F, T>> for [[D; 3]; 4]
+where
+    D: Decimal,
+    F: Unit,
+    T: Unit,
+{
+    fn from(value: Affine3<D, F, T>) -> Self {
+        value.m
+    }
+}
@@ -10,7 +10,7 @@ pub type Vector2f<U> = Vector2<f32, U>;
 pub type Vector2d<U> = Vector2<f64, U>;
 
 #[derive(
-    Debug, Default, Clone, Copy, PartialEq, Eq, CwArithmetic, CwBitops, BcArithmetic, BcBitops,
+    Debug, Default, Clone, Copy, PartialEq, Eq, Hash, CwArithmetic, CwBitops, BcArithmetic, BcBitops,
 )]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Vector2<I, U = ()>
@@ -21,7 +21,7 @@ where
     pub x: I,
     pub y: I,
     #[op_override("PhantomData")]
-    #[serde(skip)]
+    #[cfg_attr(feature = "serde", serde(skip))]
     _phantom: PhantomData<U>,
 }
 impl<I, U> Vector2<I, U>
@@ -0,0 +1,177 @@
+use std::{marker::PhantomData, ops::Neg};
+
+use stellare_types_derive::{BcArithmetic, BcBitops, CwArithmetic, CwBitops};
+
+use crate::math::{Decimal, Integer, Unit};
+
+pub type Vector3u<U> = Vector3<u32, U>;
+pub type Vector3i<U> = Vector3<i32, U>;
+pub type Vector3f<U> = Vector3<f32, U>;
+pub type Vector3d<U> = Vector3<f64, U>;
+
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Hash, CwArithmetic, CwBitops, BcArithmetic, BcBitops,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Vector3<I, U = ()>
+where
+    I: Integer,
+    U: Unit,
+{
+    pub x: I,
+    pub y: I,
+    pub z: I,
+    #[op_override("PhantomData")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    _phantom: PhantomData<U>,
+}
+impl<I, U> Vector3<I, U>
+where
+    I: Integer,
+    U: Unit,
+{
+    pub fn new(x: I, y: I, z: I) -> Vector3<I, U> {
+        Vector3 {
+            x,
+            y,
+            z,
+            _phantom: PhantomData,
+        }
+    }
+    pub fn zero() -> Self {
+        Self::splat(I::zero())
+    }
+    pub fn one() -> Self {
+        Self::splat(I::one())
+    }
+    pub fn splat(val: I) -> Self {
+        Self::new(val, val, val)
+    }
+
+    pub fn inner_into<N: Integer + From<I>>(self) -> Vector3<N, U> {
+        Vector3::new(N::from(self.x), N::from(self.y), N::from(self.z))
+    }
+    pub fn to_precise(self) -> Vector3<I::Decimal, U> {
+        Vector3::new(
+            self.x.to_precise(),
+            self.y.to_precise(),
+            self.z.to_precise(),
+        )
+    }
+
+    pub fn cross(self, other: Self) -> Self {
+        Self::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+    pub fn dot(self, other: Self) -> I {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+    pub fn length_squared(self) -> I {
+        self.dot(self)
+    }
+    pub fn length(self) -> I::Decimal {
+        self.length_squared().to_precise().sqrt()
+    }
+    /// `None` for zero or non finite vectors.
+    pub fn normalize(self) -> Option<Vector3<I::Decimal, U>> {
+        let l = self.length();
+        let p = self.to_precise();
+        let is_valid = p.x.is_number() && p.y.is_number() && p.z.is_number();
+        if !l.can_div_safe() || !is_valid {
+            return None;
+        }
+        Some(p / l)
+    }
+    pub fn distance_to(self, other: Self) -> I::Decimal {
+        (self - other).length()
+    }
+    pub fn distance_to_squared(self, other: Self) -> I {
+        (self - other).length_squared()
+    }
+
+    pub fn lerp(self, max: Self, alpha: I::Decimal) -> Vector3<I::Decimal, U> {
+        let p_min = self.to_precise();
+        let p_max = max.to_precise();
+        p_min * (I::Decimal::one() - alpha) + p_max * alpha
+    }
+    pub fn min(self, other: Self) -> Self {
+        Self::new(
+            self.x.min(other.x),
+            self.y.min(other.y),
+            self.z.min(other.z),
+        )
+    }
+    pub fn max(self, other: Self) -> Self {
+        Self::new(
+            self.x.max(other.x),
+            self.y.max(other.y),
+            self.z.max(other.z),
+        )
+    }
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        Self::new(
+            self.x.clamp(min.x, max.x),
+            self.y.clamp(min.y, max.y),
+            self.z.clamp(min.z, max.z),
+        )
+    }
+}
+
+macro_rules! impl_neg_for_signed {
+    ($($t:ty),*) => {
+        $(
+            impl<U> Neg for Vector3<$t, U>
+            where
+                U: Unit,
+            {
+                type Output = Self;
+
+                fn neg(self) -> Self::Output {
+                    Self::new(-self.x, -self.y, -self.z)
+                }
+            }
+        )*
+    };
+}
+impl_neg_for_signed!(i8, i16, i32, i64, f32, f64);
+
+impl<S, U> std::fmt::Display for Vector3<S, U>
+where
+    S: Integer + std::fmt::Display,
+    U: Unit,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[")?;
+        self.x.fmt(f)?;
+        write!(f, ", ")?;
+        self.y.fmt(f)?;
+        write!(f, ", ")?;
+        self.z.fmt(f)?;
+        write!(f, "]")?;
+
+        Ok(())
+    }
+}
+
+impl<I, U> From<Vector3<I, U>> for (I, I, I)
+where
+    I: Integer,
+    U: Unit,
+{
+    fn from(value: Vector3<I, U>) -> Self {
+        (value.x, value.y, value.z)
+    }
+}
+
+impl<I, U> From<(I, I, I)> for Vector3<I, U>
+where
+    I: Integer,
+    U: Unit,
+{
+    fn from(value: (I, I, I)) -> Self {
+        Vector3::new(value.0, value.1, value.2)
+    }
+}
@@ -0,0 +1,178 @@
+use crate::math::{
+    Affine2, ClipSpace, Decimal, Rect2, ScreenSpace, Vector2, ViewSpace, WorldSpace,
+};
+
+/// Direction the y axis of `ClipSpace` points to on screen.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ClipOrientation {
+    /// Clip y grows upwards, as in OpenGL, and is flipped on screen.
+    #[default]
+    YUp,
+    /// Clip y grows downwards like screen coordinates, as in Vulkan.
+    YDown,
+}
+
+/// Area of the screen the clip space `[-1, 1]` square is mapped to.
+#[derive(Debug, Clone, Copy)]
+pub struct Viewport<D>
+where
+    D: Decimal,
+{
+    pub rect: Rect2<D, ScreenSpace>,
+    pub orientation: ClipOrientation,
+}
+impl<D> Viewport<D>
+where
+    D: Decimal,
+{
+    pub fn new(rect: Rect2<D, ScreenSpace>, orientation: ClipOrientation) -> Self {
+        Self { rect, orientation }
+    }
+    /// Viewport covering a whole surface of `size`.
+    pub fn from_size(size: Vector2<D, ScreenSpace>) -> Self {
+        Self::new(
+            Rect2::from_size(Vector2::new(D::zero(), D::zero()), size),
+            ClipOrientation::default(),
+        )
+    }
+    /// Width over height, one for degenerate viewports.
+    pub fn aspect_ratio(&self) -> D {
+        let size = self.rect.size();
+        if size.y.can_div_safe() {
+            size.x / size.y
+        } else {
+            D::one()
+        }
+    }
+
+    pub fn clip_to_screen(&self) -> Affine2<D, ClipSpace, ScreenSpace> {
+        let two = D::one() + D::one();
+        let half = self.rect.size() / two;
+        let center = self.rect.center();
+        let sy = match self.orientation {
+            ClipOrientation::YUp => -half.y,
+            ClipOrientation::YDown => half.y,
+        };
+        Affine2::new(half.x, D::zero(), D::zero(), sy, center.x, center.y)
+    }
+    /// `None` when the viewport has no area.
+    pub fn screen_to_clip(&self) -> Option<Affine2<D, ScreenSpace, ClipSpace>> {
+        self.clip_to_screen().inverse()
+    }
+}
+
+/// Orthographic projection mapping the view space box between the planes to
+/// the clip space `[-1, 1]` square.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Orthographic<D>
+where
+    D: Decimal,
+{
+    pub left: D,
+    pub right: D,
+    pub bottom: D,
+    pub top: D,
+}
+impl<D> Orthographic<D>
+where
+    D: Decimal,
+{
+    pub fn new(left: D, right: D, bottom: D, top: D) -> Self {
+        Self {
+            left,
+            right,
+            bottom,
+            top,
+        }
+    }
+    /// Box of `size` centered on the view space origin.
+    pub fn centered(size: Vector2<D, ViewSpace>) -> Self {
+        let two = D::one() + D::one();
+        let half = size / two;
+        Self::new(-half.x, half.x, -half.y, half.y)
+    }
+    /// Centered box `height` units tall, as wide as the viewport aspect ratio
+    /// allows.
+    pub fn from_viewport(viewport: &Viewport<D>, height: D) -> Self {
+        Self::centered(Vector2::new(height * viewport.aspect_ratio(), height))
+    }
+
+    /// `None` when the box has no width or height.
+    pub fn view_to_clip(&self) -> Option<Affine2<D, ViewSpace, ClipSpace>> {
+        let width = self.right - self.left;
+        let height = self.top - self.bottom;
+        if !width.can_div_safe() || !height.can_div_safe() {
+            return None;
+        }
+        let two = D::one() + D::one();
+        Some(Affine2::new(
+            two / width,
+            D::zero(),
+            D::zero(),
+            two / height,
+            -(self.right + self.left) / width,
+            -(self.top + self.bottom) / height,
+        ))
+    }
+    pub fn clip_to_view(&self) -> Affine2<D, ClipSpace, ViewSpace> {
+        let two = D::one() + D::one();
+        let half_width = (self.right - self.left) / two;
+        let half_height = (self.top - self.bottom) / two;
+        Affine2::new(
+            half_width,
+            D::zero(),
+            D::zero(),
+            half_height,
+            self.left + half_width,
+            self.bottom + half_height,
+        )
+    }
+}
+
+/// Chain of transforms from `WorldSpace` to `ScreenSpace`.
+#[derive(Debug, Clone, Copy)]
+pub struct ViewPipeline<D>
+where
+    D: Decimal,
+{
+    pub view: Affine2<D, WorldSpace, ViewSpace>,
+    pub projection: Orthographic<D>,
+    pub viewport: Viewport<D>,
+}
+impl<D> ViewPipeline<D>
+where
+    D: Decimal,
+{
+    pub fn new(
+        view: Affine2<D, WorldSpace, ViewSpace>,
+        projection: Orthographic<D>,
+        viewport: Viewport<D>,
+    ) -> Self {
+        Self {
+            view,
+            projection,
+            viewport,
+        }
+    }
+
+    /// `None` when the projection is degenerate.
+    pub fn world_to_clip(&self) -> Option<Affine2<D, WorldSpace, ClipSpace>> {
+        Some(self.projection.view_to_clip()? * self.view)
+    }
+    pub fn world_to_screen(&self) -> Option<Affine2<D, WorldSpace, ScreenSpace>> {
+        Some(self.viewport.clip_to_screen() * self.world_to_clip()?)
+    }
+    /// `None` when any step of the chain is degenerate.
+    pub fn screen_to_world(&self) -> Option<Affine2<D, ScreenSpace, WorldSpace>> {
+        self.world_to_screen()?.inverse()
+    }
+
+    pub fn project(&self, point: Vector2<D, WorldSpace>) -> Option<Vector2<D, ScreenSpace>> {
+        Some(self.world_to_screen()?.transform_point(point))
+    }
+    pub fn unproject(&self, point: Vector2<D, ScreenSpace>) -> Option<Vector2<D, WorldSpace>> {
+        Some(self.screen_to_world()?.transform_point(point))
+    }
+}
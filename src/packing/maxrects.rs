@@ -0,0 +1,110 @@
+use crate::{
+    math::{Rect2, TexelSpace, Vector2},
+    packing::{Area, PackOptions, RectPacker},
+};
+
+/// Keeps every maximal free rectangle of the bin and places rects using the
+/// best short side fit heuristic.
+#[derive(Debug, Clone)]
+pub struct MaxRectsPacker {
+    size: Vector2<u32, TexelSpace>,
+    options: PackOptions,
+    free: Vec<Area>,
+}
+impl MaxRectsPacker {
+    fn split(&mut self, placed: &Area) {
+        let mut split = Vec::new();
+        self.free.retain(|free| {
+            if !free.intersects(placed) {
+                return true;
+            }
+            if free.x < placed.x {
+                split.push(Area {
+                    w: placed.x - free.x,
+                    ..*free
+                });
+            }
+            if placed.right() < free.right() {
+                split.push(Area {
+                    x: placed.right(),
+                    w: free.right() - placed.right(),
+                    ..*free
+                });
+            }
+            if free.y < placed.y {
+                split.push(Area {
+                    h: placed.y - free.y,
+                    ..*free
+                });
+            }
+            if placed.bottom() < free.bottom() {
+                split.push(Area {
+                    y: placed.bottom(),
+                    h: free.bottom() - placed.bottom(),
+                    ..*free
+                });
+            }
+            false
+        });
+        self.free.extend(split);
+    }
+    fn prune(&mut self) {
+        let free = std::mem::take(&mut self.free);
+        for (i, area) in free.iter().enumerate() {
+            let redundant = free
+                .iter()
+                .enumerate()
+                .any(|(j, other)| i != j && other.contains(area) && (other != area || j < i));
+            if !redundant {
+                self.free.push(*area);
+            }
+        }
+    }
+}
+impl RectPacker for MaxRectsPacker {
+    fn new(size: Vector2<u32, TexelSpace>, options: PackOptions) -> Self {
+        Self {
+            size,
+            options,
+            free: vec![Area::padded(size, options.padding)],
+        }
+    }
+    fn size(&self) -> Vector2<u32, TexelSpace> {
+        self.size
+    }
+    fn insert(&mut self, size: Vector2<u32, TexelSpace>) -> Option<(Rect2<u32, TexelSpace>, bool)> {
+        let item = Area::padded(size, self.options.padding);
+        let mut best: Option<(Area, bool, (u32, u32))> = None;
+        for free in &self.free {
+            for rotated in [false, true] {
+                if rotated && (!self.options.allow_rotation || item.w == item.h) {
+                    continue;
+                }
+                let (w, h) = if rotated {
+                    (item.h, item.w)
+                } else {
+                    (item.w, item.h)
+                };
+                if free.w < w || free.h < h {
+                    continue;
+                }
+                let (left_w, left_h) = (free.w - w, free.h - h);
+                let score = (left_w.min(left_h), left_w.max(left_h));
+                if best.is_none_or(|(_, _, best)| score < best) {
+                    let area = Area {
+                        x: free.x,
+                        y: free.y,
+                        w,
+                        h,
+                    };
+                    best = Some((area, rotated, score));
+                }
+            }
+        }
+
+        let (placed, rotated, _) = best?;
+        self.split(&placed);
+        self.prune();
+        Some((placed.unpadded(size, rotated), rotated))
+    }
+}
@@ -0,0 +1,164 @@
+pub mod maxrects;
+pub mod skyline;
+
+pub use crate::packing::{maxrects::MaxRectsPacker, skyline::SkylinePacker};
+
+use crate::math::{Rect2, TexelSpace, Vector2};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PackOptions {
+    /// Space left between placed rects, the edges of the bin are not padded.
+    pub padding: u32,
+    /// Allows rects to be rotated by 90 degrees when that fits better.
+    pub allow_rotation: bool,
+    /// Largest size the bin may grow to when not everything fits, the bin
+    /// never grows if this is `None`.
+    pub max_size: Option<Vector2<u32, TexelSpace>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Placement {
+    /// Index of the size this placement was made for.
+    pub index: usize,
+    /// Placed area, already rotated if `rotated` is set.
+    pub rect: Rect2<u32, TexelSpace>,
+    pub rotated: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct PackResult {
+    /// Final size of the bin.
+    pub size: Vector2<u32, TexelSpace>,
+    pub placements: Vec<Placement>,
+    /// Indices of the sizes that did not fit.
+    pub unplaced: Vec<usize>,
+}
+
+pub trait RectPacker {
+    fn new(size: Vector2<u32, TexelSpace>, options: PackOptions) -> Self;
+    fn size(&self) -> Vector2<u32, TexelSpace>;
+    /// Places a single rect, returns the placed area and whether it was
+    /// rotated.
+    fn insert(&mut self, size: Vector2<u32, TexelSpace>) -> Option<(Rect2<u32, TexelSpace>, bool)>;
+}
+
+/// Packs every size into a bin of `size`, largest first.
+///
+/// If something doesn't fit and `options.max_size` allows it, the bin is
+/// grown by doubling its shorter side and packing starts over.
+pub fn pack<P>(
+    sizes: &[Vector2<u32, TexelSpace>],
+    size: Vector2<u32, TexelSpace>,
+    options: PackOptions,
+) -> PackResult
+where
+    P: RectPacker,
+{
+    let mut order: Vec<usize> = (0..sizes.len()).collect();
+    order.sort_by_key(|&idx| {
+        let size = sizes[idx];
+        std::cmp::Reverse((size.x.max(size.y), size.x.min(size.y)))
+    });
+
+    let mut size = size;
+    loop {
+        let mut packer = P::new(size, options);
+        let mut placements = Vec::with_capacity(sizes.len());
+        let mut unplaced = Vec::new();
+        for &idx in &order {
+            match packer.insert(sizes[idx]) {
+                Some((rect, rotated)) => placements.push(Placement {
+                    index: idx,
+                    rect,
+                    rotated,
+                }),
+                None => unplaced.push(idx),
+            }
+        }
+
+        let grown = options
+            .max_size
+            .and_then(|max_size| grow(size, max_size))
+            .filter(|_| !unplaced.is_empty());
+        match grown {
+            Some(grown) => size = grown,
+            None => {
+                placements.sort_by_key(|placement| placement.index);
+                unplaced.sort_unstable();
+                return PackResult {
+                    size,
+                    placements,
+                    unplaced,
+                };
+            }
+        }
+    }
+}
+
+fn grow(
+    size: Vector2<u32, TexelSpace>,
+    max_size: Vector2<u32, TexelSpace>,
+) -> Option<Vector2<u32, TexelSpace>> {
+    let double = |value: u32, max: u32| value.max(1).saturating_mul(2).min(max);
+    // Axes already at or past their max are left alone, the bin never
+    // shrinks.
+    let grow_x = match (size.x < max_size.x, size.y < max_size.y) {
+        (false, false) => return None,
+        (true, true) => size.x <= size.y,
+        (grow_x, _) => grow_x,
+    };
+    Some(if grow_x {
+        Vector2::new(double(size.x, max_size.x), size.y)
+    } else {
+        Vector2::new(size.x, double(size.y, max_size.y))
+    })
+}
+
+/// Padded area used internally by the packers, placed rects own `padding`
+/// extra texels on their right and bottom sides and the bin is enlarged by the
+/// same amount to compensate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Area {
+    x: u32,
+    y: u32,
+    w: u32,
+    h: u32,
+}
+impl Area {
+    fn padded(size: Vector2<u32, TexelSpace>, padding: u32) -> Self {
+        Self {
+            x: 0,
+            y: 0,
+            w: size.x.saturating_add(padding),
+            h: size.y.saturating_add(padding),
+        }
+    }
+    fn right(&self) -> u32 {
+        self.x.saturating_add(self.w)
+    }
+    fn bottom(&self) -> u32 {
+        self.y.saturating_add(self.h)
+    }
+    fn contains(&self, other: &Area) -> bool {
+        other.x >= self.x
+            && other.y >= self.y
+            && other.right() <= self.right()
+            && other.bottom() <= self.bottom()
+    }
+    fn intersects(&self, other: &Area) -> bool {
+        self.x < other.right()
+            && other.x < self.right()
+            && self.y < other.bottom()
+            && other.y < self.bottom()
+    }
+    /// Rect of the item of `size` placed at this area. The size is passed in
+    /// because `padded` may have saturated and lost it.
+    fn unpadded(&self, size: Vector2<u32, TexelSpace>, rotated: bool) -> Rect2<u32, TexelSpace> {
+        let size = if rotated {
+            Vector2::new(size.y, size.x)
+        } else {
+            size
+        };
+        Rect2::from_size(Vector2::new(self.x, self.y), size)
+    }
+}
@@ -0,0 +1,144 @@
+use crate::{
+    math::{Rect2, TexelSpace, Vector2},
+    packing::{Area, PackOptions, RectPacker},
+};
+
+#[derive(Debug, Clone, Copy)]
+struct Segment {
+    x: u32,
+    y: u32,
+    w: u32,
+}
+
+/// Tracks the top edge of the packed rects as a list of horizontal segments
+/// and places rects using the bottom-left heuristic. Faster than
+/// `MaxRectsPacker` at the cost of wasting the space under overhangs.
+#[derive(Debug, Clone)]
+pub struct SkylinePacker {
+    size: Vector2<u32, TexelSpace>,
+    options: PackOptions,
+    bin: Area,
+    skyline: Vec<Segment>,
+}
+impl SkylinePacker {
+    /// Height at which a rect of width `w` rests when its left side is placed
+    /// at the start of segment `idx`.
+    fn fit(&self, idx: usize, w: u32, h: u32) -> Option<u32> {
+        let start = self.skyline[idx];
+        if start.x.checked_add(w)? > self.bin.w {
+            return None;
+        }
+        let mut y = start.y;
+        let mut covered: u32 = 0;
+        let mut i = idx;
+        while covered < w {
+            let segment = self.skyline.get(i)?;
+            y = y.max(segment.y);
+            covered = covered.checked_add(segment.w)?;
+            i += 1;
+        }
+        (y.checked_add(h)? <= self.bin.h).then_some(y)
+    }
+    fn add_level(&mut self, idx: usize, placed: &Area) {
+        self.skyline.insert(
+            idx,
+            Segment {
+                x: placed.x,
+                y: placed.bottom(),
+                w: placed.w,
+            },
+        );
+        let i = idx + 1;
+        while i < self.skyline.len() && self.skyline[i].x < placed.right() {
+            let overlap = placed.right() - self.skyline[i].x;
+            if self.skyline[i].w <= overlap {
+                self.skyline.remove(i);
+            } else {
+                self.skyline[i].x += overlap;
+                self.skyline[i].w -= overlap;
+                break;
+            }
+        }
+        for i in (1..self.skyline.len()).rev() {
+            if self.skyline[i - 1].y == self.skyline[i].y {
+                self.skyline[i - 1].w += self.skyline[i].w;
+                self.skyline.remove(i);
+            }
+        }
+    }
+}
+impl RectPacker for SkylinePacker {
+    fn new(size: Vector2<u32, TexelSpace>, options: PackOptions) -> Self {
+        let bin = Area::padded(size, options.padding);
+        Self {
+            size,
+            options,
+            bin,
+            skyline: vec![Segment {
+                x: 0,
+                y: 0,
+                w: bin.w,
+            }],
+        }
+    }
+    fn size(&self) -> Vector2<u32, TexelSpace> {
+        self.size
+    }
+    fn insert(&mut self, size: Vector2<u32, TexelSpace>) -> Option<(Rect2<u32, TexelSpace>, bool)> {
+        let item = Area::padded(size, self.options.padding);
+        let mut best: Option<(usize, Area, bool)> = None;
+        for idx in 0..self.skyline.len() {
+            for rotated in [false, true] {
+                if rotated && (!self.options.allow_rotation || item.w == item.h) {
+                    continue;
+                }
+                let (w, h) = if rotated {
+                    (item.h, item.w)
+                } else {
+                    (item.w, item.h)
+                };
+                let Some(y) = self.fit(idx, w, h) else {
+                    continue;
+                };
+                let area = Area {
+                    x: self.skyline[idx].x,
+                    y,
+                    w,
+                    h,
+                };
+                if best.is_none_or(|(_, best, _)| (area.bottom(), area.x) < (best.bottom(), best.x))
+                {
+                    best = Some((idx, area, rotated));
+                }
+            }
+        }
+
+        let (idx, placed, rotated) = best?;
+        self.add_level(idx, &placed);
+        Some((placed.unpadded(size, rotated), rotated))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn oversized_after_small() {
+        let mut packer = SkylinePacker::new(Vector2::new(64, 64), PackOptions::default());
+        assert!(packer.insert(Vector2::new(10, 10)).is_some());
+        assert!(packer.insert(Vector2::new(u32::MAX - 5, 1)).is_none());
+        assert!(packer.insert(Vector2::new(1, u32::MAX - 5)).is_none());
+    }
+
+    #[test]
+    fn saturated_padding_keeps_the_size() {
+        let options = PackOptions {
+            padding: 4,
+            ..Default::default()
+        };
+        let mut packer = SkylinePacker::new(Vector2::new(u32::MAX, 8), options);
+        let (rect, _) = packer.insert(Vector2::new(u32::MAX - 2, 4)).unwrap();
+        assert_eq!(rect.size(), Vector2::new(u32::MAX - 2, 4));
+    }
+}
@@ -0,0 +1,423 @@
+use crate::{
+    math::{Decimal, Rect2, Unit, Vector2},
+    spatial::ProxyId,
+};
+
+#[derive(Debug, Clone)]
+enum NodeKind<T> {
+    Leaf(T),
+    Branch([usize; 2]),
+    Free(Option<usize>),
+}
+
+#[derive(Debug, Clone)]
+struct Node<D, T, U>
+where
+    D: Decimal,
+    U: Unit,
+{
+    rect: Rect2<D, U>,
+    parent: Option<usize>,
+    height: usize,
+    kind: NodeKind<T>,
+}
+
+/// Dynamic bounding volume hierarchy, leaves store their bounds enlarged by
+/// `margin` so small movements don't require touching the tree.
+#[derive(Debug, Clone)]
+pub struct Bvh2<D, T, U = ()>
+where
+    D: Decimal,
+    U: Unit,
+{
+    nodes: Vec<Node<D, T, U>>,
+    root: Option<usize>,
+    free: Option<usize>,
+    margin: D,
+    len: usize,
+}
+impl<D, T, U> Bvh2<D, T, U>
+where
+    D: Decimal,
+    U: Unit,
+{
+    pub fn new(margin: D) -> Self {
+        Self {
+            nodes: Vec::new(),
+            root: None,
+            free: None,
+            margin: margin.max(D::zero()),
+            len: 0,
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    #[inline]
+    pub fn margin(&self) -> D {
+        self.margin
+    }
+    pub fn height(&self) -> usize {
+        self.root.map_or(0, |root| self.nodes[root].height)
+    }
+
+    pub fn insert(&mut self, rect: Rect2<D, U>, data: T) -> ProxyId {
+        let leaf = self.allocate(Node {
+            rect: fatten(&rect, self.margin),
+            parent: None,
+            height: 0,
+            kind: NodeKind::Leaf(data),
+        });
+        self.insert_leaf(leaf);
+        self.len += 1;
+        ProxyId(leaf)
+    }
+    pub fn remove(&mut self, id: ProxyId) -> Option<T> {
+        self.leaf(id)?;
+        self.remove_leaf(id.0);
+        self.len -= 1;
+        let kind = std::mem::replace(&mut self.nodes[id.0].kind, NodeKind::Free(self.free));
+        self.free = Some(id.0);
+        match kind {
+            NodeKind::Leaf(data) => Some(data),
+            _ => None,
+        }
+    }
+    /// Updates the bounds of a proxy, returns `true` if the proxy had to be
+    /// reinserted because it left (or became much smaller than) its fat bounds.
+    pub fn move_proxy(&mut self, id: ProxyId, rect: Rect2<D, U>) -> bool {
+        let Some(node) = self.leaf(id) else {
+            return false;
+        };
+        let four = D::one() + D::one() + D::one() + D::one();
+        let huge = fatten(&rect, self.margin * four);
+        if node.rect.contains_rect(&rect) && huge.contains_rect(&node.rect) {
+            return false;
+        }
+        self.remove_leaf(id.0);
+        self.nodes[id.0].rect = fatten(&rect, self.margin);
+        self.insert_leaf(id.0);
+        true
+    }
+
+    pub fn get(&self, id: ProxyId) -> Option<&T> {
+        match &self.leaf(id)?.kind {
+            NodeKind::Leaf(data) => Some(data),
+            _ => None,
+        }
+    }
+    pub fn get_mut(&mut self, id: ProxyId) -> Option<&mut T> {
+        match &mut self.nodes.get_mut(id.0)?.kind {
+            NodeKind::Leaf(data) => Some(data),
+            _ => None,
+        }
+    }
+    pub fn fat_rect(&self, id: ProxyId) -> Option<Rect2<D, U>> {
+        self.leaf(id).map(|node| node.rect)
+    }
+    pub fn iter(&self) -> impl Iterator<Item = (ProxyId, &T)> {
+        self.nodes
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, node)| match &node.kind {
+                NodeKind::Leaf(data) => Some((ProxyId(idx), data)),
+                _ => None,
+            })
+    }
+
+    /// Proxies whose fat bounds intersect `area`.
+    pub fn query(&self, area: Rect2<D, U>) -> Query<'_, D, T, U> {
+        Query {
+            tree: self,
+            area,
+            stack: self.root.into_iter().collect(),
+        }
+    }
+    /// Proxies whose fat bounds are hit by the segment going from `origin` to
+    /// `end`, along with the segment parameter at which it enters them.
+    pub fn ray_query(&self, origin: Vector2<D, U>, end: Vector2<D, U>) -> RayQuery<'_, D, T, U> {
+        RayQuery {
+            tree: self,
+            origin,
+            end,
+            stack: self.root.into_iter().collect(),
+        }
+    }
+    /// Every pair of proxies whose fat bounds overlap, each pair is reported
+    /// once with the smaller id first.
+    pub fn overlapping_pairs(&self) -> Vec<(ProxyId, ProxyId)> {
+        let mut pairs = Vec::new();
+        for (idx, node) in self.nodes.iter().enumerate() {
+            if !matches!(node.kind, NodeKind::Leaf(_)) {
+                continue;
+            }
+            for (other, _) in self.query(node.rect) {
+                if other.0 > idx {
+                    pairs.push((ProxyId(idx), other));
+                }
+            }
+        }
+        pairs
+    }
+
+    fn leaf(&self, id: ProxyId) -> Option<&Node<D, T, U>> {
+        self.nodes
+            .get(id.0)
+            .filter(|node| matches!(node.kind, NodeKind::Leaf(_)))
+    }
+    fn children(&self, idx: usize) -> [usize; 2] {
+        match self.nodes[idx].kind {
+            NodeKind::Branch(children) => children,
+            _ => unreachable!("Only branches have children"),
+        }
+    }
+    fn allocate(&mut self, node: Node<D, T, U>) -> usize {
+        match self.free {
+            Some(idx) => {
+                if let NodeKind::Free(next) = self.nodes[idx].kind {
+                    self.free = next;
+                }
+                self.nodes[idx] = node;
+                idx
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        }
+    }
+    fn release(&mut self, idx: usize) {
+        self.nodes[idx].kind = NodeKind::Free(self.free);
+        self.nodes[idx].parent = None;
+        self.free = Some(idx);
+    }
+    fn replace_child(&mut self, parent: usize, old: usize, new: usize) {
+        let mut children = self.children(parent);
+        for child in &mut children {
+            if *child == old {
+                *child = new;
+            }
+        }
+        self.nodes[parent].kind = NodeKind::Branch(children);
+    }
+
+    fn insert_leaf(&mut self, leaf: usize) {
+        let Some(root) = self.root else {
+            self.nodes[leaf].parent = None;
+            self.root = Some(leaf);
+            return;
+        };
+
+        // Descend using the surface area heuristic (perimeter in 2D).
+        let rect = self.nodes[leaf].rect;
+        let mut sibling = root;
+        while let NodeKind::Branch(children) = self.nodes[sibling].kind {
+            let area = half_perimeter(&self.nodes[sibling].rect);
+            let combined = half_perimeter(&merged(&self.nodes[sibling].rect, &rect));
+            let cost = combined + combined;
+            let inheritance = (combined - area) + (combined - area);
+
+            let [cost0, cost1] = children.map(|child| {
+                let node = &self.nodes[child];
+                let enlarged = half_perimeter(&merged(&node.rect, &rect));
+                match node.kind {
+                    NodeKind::Branch(_) => enlarged - half_perimeter(&node.rect) + inheritance,
+                    _ => enlarged + inheritance,
+                }
+            });
+            if cost < cost0 && cost < cost1 {
+                break;
+            }
+            sibling = if cost0 < cost1 {
+                children[0]
+            } else {
+                children[1]
+            };
+        }
+
+        let old_parent = self.nodes[sibling].parent;
+        let parent = self.allocate(Node {
+            rect: merged(&self.nodes[sibling].rect, &rect),
+            parent: old_parent,
+            height: self.nodes[sibling].height + 1,
+            kind: NodeKind::Branch([sibling, leaf]),
+        });
+        self.nodes[sibling].parent = Some(parent);
+        self.nodes[leaf].parent = Some(parent);
+        match old_parent {
+            Some(old_parent) => self.replace_child(old_parent, sibling, parent),
+            None => self.root = Some(parent),
+        }
+        self.refit(Some(parent));
+    }
+    fn remove_leaf(&mut self, leaf: usize) {
+        if self.root == Some(leaf) {
+            self.root = None;
+            return;
+        }
+        let Some(parent) = self.nodes[leaf].parent else {
+            return;
+        };
+        let [c0, c1] = self.children(parent);
+        let sibling = if c0 == leaf { c1 } else { c0 };
+        let grandparent = self.nodes[parent].parent;
+
+        self.nodes[sibling].parent = grandparent;
+        self.nodes[leaf].parent = None;
+        match grandparent {
+            Some(grandparent) => self.replace_child(grandparent, parent, sibling),
+            None => self.root = Some(sibling),
+        }
+        self.release(parent);
+        self.refit(grandparent);
+    }
+    fn refit(&mut self, mut idx: Option<usize>) {
+        while let Some(current) = idx {
+            let current = self.balance(current);
+            self.fit(current);
+            idx = self.nodes[current].parent;
+        }
+    }
+    fn fit(&mut self, idx: usize) {
+        let [c0, c1] = self.children(idx);
+        self.nodes[idx].height = 1 + self.nodes[c0].height.max(self.nodes[c1].height);
+        self.nodes[idx].rect = merged(&self.nodes[c0].rect, &self.nodes[c1].rect);
+    }
+    fn balance(&mut self, idx: usize) -> usize {
+        let NodeKind::Branch([c0, c1]) = self.nodes[idx].kind else {
+            return idx;
+        };
+        let (h0, h1) = (self.nodes[c0].height, self.nodes[c1].height);
+        if h1 > h0 + 1 {
+            self.rotate(idx, 1)
+        } else if h0 > h1 + 1 {
+            self.rotate(idx, 0)
+        } else {
+            idx
+        }
+    }
+    /// Promotes the child at `slot` to take the place of `idx`, `idx` keeps the
+    /// shorter grandchild and the taller one moves up with the promoted node.
+    fn rotate(&mut self, idx: usize, slot: usize) -> usize {
+        let mut children = self.children(idx);
+        let up = children[slot];
+        let [g0, g1] = self.children(up);
+        let (tall, short) = if self.nodes[g0].height > self.nodes[g1].height {
+            (g0, g1)
+        } else {
+            (g1, g0)
+        };
+
+        let grandparent = self.nodes[idx].parent;
+        self.nodes[up].parent = grandparent;
+        self.nodes[idx].parent = Some(up);
+        match grandparent {
+            Some(grandparent) => self.replace_child(grandparent, idx, up),
+            None => self.root = Some(up),
+        }
+
+        children[slot] = short;
+        self.nodes[short].parent = Some(idx);
+        self.nodes[idx].kind = NodeKind::Branch(children);
+        self.nodes[up].kind = NodeKind::Branch([idx, tall]);
+        self.fit(idx);
+        self.fit(up);
+        up
+    }
+}
+
+pub struct Query<'a, D, T, U>
+where
+    D: Decimal,
+    U: Unit,
+{
+    tree: &'a Bvh2<D, T, U>,
+    area: Rect2<D, U>,
+    stack: Vec<usize>,
+}
+impl<'a, D, T, U> Iterator for Query<'a, D, T, U>
+where
+    D: Decimal,
+    U: Unit,
+{
+    type Item = (ProxyId, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(idx) = self.stack.pop() {
+            let node = &self.tree.nodes[idx];
+            if !node.rect.intersects(&self.area) {
+                continue;
+            }
+            match &node.kind {
+                NodeKind::Leaf(data) => return Some((ProxyId(idx), data)),
+                NodeKind::Branch(children) => self.stack.extend(children),
+                NodeKind::Free(_) => {}
+            }
+        }
+        None
+    }
+}
+
+pub struct RayQuery<'a, D, T, U>
+where
+    D: Decimal,
+    U: Unit,
+{
+    tree: &'a Bvh2<D, T, U>,
+    origin: Vector2<D, U>,
+    end: Vector2<D, U>,
+    stack: Vec<usize>,
+}
+impl<'a, D, T, U> Iterator for RayQuery<'a, D, T, U>
+where
+    D: Decimal,
+    U: Unit,
+{
+    type Item = (ProxyId, &'a T, D);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(idx) = self.stack.pop() {
+            let node = &self.tree.nodes[idx];
+            let Some(clip) = node.rect.clip_segment(self.origin, self.end) else {
+                continue;
+            };
+            match &node.kind {
+                NodeKind::Leaf(data) => return Some((ProxyId(idx), data, clip.t_enter)),
+                NodeKind::Branch(children) => self.stack.extend(children),
+                NodeKind::Free(_) => {}
+            }
+        }
+        None
+    }
+}
+
+fn fatten<D, U>(rect: &Rect2<D, U>, margin: D) -> Rect2<D, U>
+where
+    D: Decimal,
+    U: Unit,
+{
+    Rect2::new(rect.min() - margin, rect.max() + margin)
+}
+fn merged<D, U>(a: &Rect2<D, U>, b: &Rect2<D, U>) -> Rect2<D, U>
+where
+    D: Decimal,
+    U: Unit,
+{
+    let mut rect = *a;
+    rect.union(b);
+    rect
+}
+fn half_perimeter<D, U>(rect: &Rect2<D, U>) -> D
+where
+    D: Decimal,
+    U: Unit,
+{
+    let size = rect.size();
+    size.x + size.y
+}
@@ -0,0 +1,177 @@
+use std::collections::HashMap;
+
+use crate::{
+    math::{Decimal, Rect2, Unit, Vector, Vector2},
+    spatial::ProxyId,
+};
+
+/// Uniform grid that buckets positions into square cells of `cell_size`,
+/// cells are only allocated while they hold items.
+#[derive(Debug, Clone)]
+pub struct SpatialHash<D, T, U = ()>
+where
+    D: Decimal,
+    U: Unit,
+{
+    cell_size: D,
+    cells: HashMap<Vector2<i32, U>, Vec<usize>>,
+    items: Vec<Option<(Vector2<D, U>, T)>>,
+    free: Vec<usize>,
+}
+impl<D, T, U> SpatialHash<D, T, U>
+where
+    D: Decimal,
+    U: Unit + Eq + std::hash::Hash,
+{
+    /// # Panics
+    /// If `cell_size` isn't a positive number.
+    pub fn new(cell_size: D) -> Self {
+        assert!(
+            cell_size.can_div_safe() && cell_size > D::zero(),
+            "Cell size must be a positive number"
+        );
+        Self {
+            cell_size,
+            cells: HashMap::new(),
+            items: Vec::new(),
+            free: Vec::new(),
+        }
+    }
+
+    #[inline]
+    pub fn cell_size(&self) -> D {
+        self.cell_size
+    }
+    pub fn len(&self) -> usize {
+        self.items.len() - self.free.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn cell_of(&self, position: Vector2<D, U>) -> Vector2<i32, U> {
+        Vector2::new(
+            (position.x / self.cell_size).floor().to_i32(),
+            (position.y / self.cell_size).floor().to_i32(),
+        )
+    }
+    /// Area covered by `cell` in the same space as the positions.
+    pub fn cell_rect(&self, cell: Vector2<i32, U>) -> Rect2<D, U> {
+        let min = Vector2::new(
+            D::from_i32(cell.x) * self.cell_size,
+            D::from_i32(cell.y) * self.cell_size,
+        );
+        Rect2::from_size(min, Vector2::splat(self.cell_size))
+    }
+
+    pub fn insert(&mut self, position: Vector2<D, U>, data: T) -> ProxyId {
+        let idx = match self.free.pop() {
+            Some(idx) => {
+                self.items[idx] = Some((position, data));
+                idx
+            }
+            None => {
+                self.items.push(Some((position, data)));
+                self.items.len() - 1
+            }
+        };
+        self.cells
+            .entry(self.cell_of(position))
+            .or_default()
+            .push(idx);
+        ProxyId(idx)
+    }
+    pub fn remove(&mut self, id: ProxyId) -> Option<T> {
+        let (position, data) = self.items.get_mut(id.0)?.take()?;
+        self.unlink(self.cell_of(position), id.0);
+        self.free.push(id.0);
+        Some(data)
+    }
+    pub fn move_item(&mut self, id: ProxyId, position: Vector2<D, U>) {
+        let Some(Some((old, _))) = self.items.get(id.0) else {
+            return;
+        };
+        let (from, to) = (self.cell_of(*old), self.cell_of(position));
+        if from != to {
+            self.unlink(from, id.0);
+            self.cells.entry(to).or_default().push(id.0);
+        }
+        if let Some(Some((old, _))) = self.items.get_mut(id.0) {
+            *old = position;
+        }
+    }
+    pub fn clear(&mut self) {
+        self.cells.clear();
+        self.items.clear();
+        self.free.clear();
+    }
+
+    pub fn get(&self, id: ProxyId) -> Option<&T> {
+        self.items.get(id.0)?.as_ref().map(|(_, data)| data)
+    }
+    pub fn get_mut(&mut self, id: ProxyId) -> Option<&mut T> {
+        self.items.get_mut(id.0)?.as_mut().map(|(_, data)| data)
+    }
+    pub fn position(&self, id: ProxyId) -> Option<Vector2<D, U>> {
+        self.items
+            .get(id.0)?
+            .as_ref()
+            .map(|(position, _)| *position)
+    }
+    pub fn iter(&self) -> impl Iterator<Item = (ProxyId, Vector2<D, U>, &T)> {
+        self.items.iter().enumerate().filter_map(|(idx, item)| {
+            item.as_ref()
+                .map(|(position, data)| (ProxyId(idx), *position, data))
+        })
+    }
+
+    /// Items stored in `cell`.
+    pub fn cell(
+        &self,
+        cell: Vector2<i32, U>,
+    ) -> impl Iterator<Item = (ProxyId, Vector2<D, U>, &T)> {
+        self.cells
+            .get(&cell)
+            .into_iter()
+            .flatten()
+            .filter_map(|&idx| {
+                self.items[idx]
+                    .as_ref()
+                    .map(|(position, data)| (ProxyId(idx), *position, data))
+            })
+    }
+    /// Items stored in every cell that overlaps `area`, items are not tested
+    /// against `area` itself.
+    pub fn query_cells(
+        &self,
+        area: Rect2<D, U>,
+    ) -> impl Iterator<Item = (ProxyId, Vector2<D, U>, &T)> {
+        let min = self.cell_of(area.min());
+        let max = self.cell_of(area.max());
+        (min.y..=max.y)
+            .flat_map(move |y| (min.x..=max.x).map(move |x| Vector2::new(x, y)))
+            .flat_map(|cell| self.cell(cell))
+    }
+    /// Items within `radius` of `center`, inclusive.
+    pub fn query_radius(
+        &self,
+        center: Vector2<D, U>,
+        radius: D,
+    ) -> impl Iterator<Item = (ProxyId, Vector2<D, U>, &T)> {
+        let area = Rect2::new(center - radius, center + radius);
+        let radius_squared = radius * radius;
+        self.query_cells(area)
+            .filter(move |(_, position, _)| position.distance_to_squared(center) <= radius_squared)
+    }
+
+    fn unlink(&mut self, cell: Vector2<i32, U>, idx: usize) {
+        let Some(bucket) = self.cells.get_mut(&cell) else {
+            return;
+        };
+        if let Some(pos) = bucket.iter().position(|&e| e == idx) {
+            bucket.swap_remove(pos);
+        }
+        if bucket.is_empty() {
+            self.cells.remove(&cell);
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use std::cmp::Ordering;
+
+use crate::math::{Decimal, Unit, Vector, Vector2};
+
+/// Static 2D tree built in bulk, points are stored in a single buffer where
+/// every subslice is split around its median.
+#[derive(Debug, Clone)]
+pub struct KdTree2<D, T, U = ()>
+where
+    D: Decimal,
+    U: Unit,
+{
+    points: Vec<(Vector2<D, U>, T)>,
+}
+impl<D, T, U> KdTree2<D, T, U>
+where
+    D: Decimal,
+    U: Unit,
+{
+    pub fn new<P>(points: P) -> Self
+    where
+        P: IntoIterator<Item = (Vector2<D, U>, T)>,
+    {
+        let mut points: Vec<_> = points.into_iter().collect();
+        build(&mut points, 0);
+        Self { points }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+    pub fn iter(&self) -> impl Iterator<Item = (Vector2<D, U>, &T)> {
+        self.points.iter().map(|(point, data)| (*point, data))
+    }
+
+    pub fn nearest(&self, target: Vector2<D, U>) -> Option<(Vector2<D, U>, &T)> {
+        self.k_nearest(target, 1).pop()
+    }
+    /// Up to `k` points closest to `target`, sorted from nearest to farthest.
+    pub fn k_nearest(&self, target: Vector2<D, U>, k: usize) -> Vec<(Vector2<D, U>, &T)> {
+        if k == 0 {
+            return Vec::new();
+        }
+        let mut best = Vec::with_capacity(k + 1);
+        self.search_nearest(0, self.points.len(), 0, target, k, &mut best);
+        best.into_iter()
+            .map(|(_, idx)| {
+                let (point, data) = &self.points[idx];
+                (*point, data)
+            })
+            .collect()
+    }
+    /// Every point within `radius` of `target`, inclusive and in no particular
+    /// order.
+    pub fn within_radius(&self, target: Vector2<D, U>, radius: D) -> Vec<(Vector2<D, U>, &T)> {
+        let mut found = Vec::new();
+        self.search_radius(0, self.points.len(), 0, target, radius * radius, &mut found);
+        found
+    }
+
+    fn search_nearest(
+        &self,
+        start: usize,
+        end: usize,
+        depth: usize,
+        target: Vector2<D, U>,
+        k: usize,
+        best: &mut Vec<(D, usize)>,
+    ) {
+        if start >= end {
+            return;
+        }
+        let mid = start + (end - start) / 2;
+        let point = self.points[mid].0;
+        let distance = point.distance_to_squared(target);
+        if best.len() < k || distance < best[best.len() - 1].0 {
+            let at = best.partition_point(|(d, _)| *d <= distance);
+            best.insert(at, (distance, mid));
+            best.truncate(k);
+        }
+
+        let split = axis(target, depth) - axis(point, depth);
+        let (near, far) = if split < D::zero() {
+            ((start, mid), (mid + 1, end))
+        } else {
+            ((mid + 1, end), (start, mid))
+        };
+        self.search_nearest(near.0, near.1, depth + 1, target, k, best);
+        if best.len() < k || split * split < best[best.len() - 1].0 {
+            self.search_nearest(far.0, far.1, depth + 1, target, k, best);
+        }
+    }
+    fn search_radius<'a>(
+        &'a self,
+        start: usize,
+        end: usize,
+        depth: usize,
+        target: Vector2<D, U>,
+        radius_squared: D,
+        found: &mut Vec<(Vector2<D, U>, &'a T)>,
+    ) {
+        if start >= end {
+            return;
+        }
+        let mid = start + (end - start) / 2;
+        let (point, data) = &self.points[mid];
+        if point.distance_to_squared(target) <= radius_squared {
+            found.push((*point, data));
+        }
+
+        // Points before `mid` are never past the split and points after it
+        // never before it, so each side only needs to be visited if the
+        // radius reaches across.
+        let split = axis(target, depth) - axis(*point, depth);
+        if split <= D::zero() || split * split <= radius_squared {
+            self.search_radius(start, mid, depth + 1, target, radius_squared, found);
+        }
+        if split >= D::zero() || split * split <= radius_squared {
+            self.search_radius(mid + 1, end, depth + 1, target, radius_squared, found);
+        }
+    }
+}
+
+impl<D, T, U> FromIterator<(Vector2<D, U>, T)> for KdTree2<D, T, U>
+where
+    D: Decimal,
+    U: Unit,
+{
+    fn from_iter<P: IntoIterator<Item = (Vector2<D, U>, T)>>(iter: P) -> Self {
+        Self::new(iter)
+    }
+}
+
+fn axis<D, U>(point: Vector2<D, U>, depth: usize) -> D
+where
+    D: Decimal,
+    U: Unit,
+{
+    if depth.is_multiple_of(2) {
+        point.x
+    } else {
+        point.y
+    }
+}
+fn build<D, T, U>(points: &mut [(Vector2<D, U>, T)], depth: usize)
+where
+    D: Decimal,
+    U: Unit,
+{
+    if points.len() <= 1 {
+        return;
+    }
+    let mid = points.len() / 2;
+    points.select_nth_unstable_by(mid, |(a, _), (b, _)| {
+        axis(*a, depth)
+            .partial_cmp(&axis(*b, depth))
+            .unwrap_or(Ordering::Equal)
+    });
+    let (left, right) = points.split_at_mut(mid);
+    build(left, depth + 1);
+    build(&mut right[1..], depth + 1);
+}
@@ -0,0 +1,14 @@
+pub mod bvh;
+pub mod hash;
+pub mod kdtree;
+pub mod sap;
+
+pub use crate::spatial::{
+    bvh::Bvh2,
+    hash::SpatialHash,
+    kdtree::KdTree2,
+    sap::{Axis, PairEvent, SweepAndPrune},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ProxyId(usize);
@@ -0,0 +1,272 @@
+use std::collections::HashSet;
+
+use crate::{
+    math::{Decimal, Rect2, Unit},
+    spatial::ProxyId,
+};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Axis {
+    #[default]
+    X,
+    Y,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PairEvent {
+    Begin(ProxyId, ProxyId),
+    End(ProxyId, ProxyId),
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Endpoint<D> {
+    value: D,
+    proxy: usize,
+    is_min: bool,
+}
+impl<D> Endpoint<D>
+where
+    D: Decimal,
+{
+    // Mins sort before maxes on ties so touching rects count as overlapping,
+    // the same way `Rect2::intersects` does.
+    fn sorts_after(&self, other: &Self) -> bool {
+        self.value > other.value || (self.value == other.value && !self.is_min && other.is_min)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Proxy<D, T, U>
+where
+    D: Decimal,
+    U: Unit,
+{
+    rect: Rect2<D, U>,
+    data: T,
+    min_endpoint: usize,
+    max_endpoint: usize,
+    partners: HashSet<usize>,
+    dirty: bool,
+}
+
+/// Sort based broadphase, keeps the rect intervals along `axis` sorted and
+/// reports pairs as they begin or stop overlapping.
+///
+/// Changes are applied lazily, `update` re-sorts the intervals and returns the
+/// events produced since the last call.
+#[derive(Debug, Clone)]
+pub struct SweepAndPrune<D, T, U = ()>
+where
+    D: Decimal,
+    U: Unit,
+{
+    axis: Axis,
+    proxies: Vec<Option<Proxy<D, T, U>>>,
+    free: Vec<usize>,
+    endpoints: Vec<Endpoint<D>>,
+    overlapping: HashSet<(usize, usize)>,
+    events: Vec<PairEvent>,
+}
+impl<D, T, U> SweepAndPrune<D, T, U>
+where
+    D: Decimal,
+    U: Unit,
+{
+    pub fn new(axis: Axis) -> Self {
+        Self {
+            axis,
+            proxies: Vec::new(),
+            free: Vec::new(),
+            endpoints: Vec::new(),
+            overlapping: HashSet::new(),
+            events: Vec::new(),
+        }
+    }
+
+    #[inline]
+    pub fn axis(&self) -> Axis {
+        self.axis
+    }
+    pub fn len(&self) -> usize {
+        self.proxies.len() - self.free.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn insert(&mut self, rect: Rect2<D, U>, data: T) -> ProxyId {
+        let idx = self.free.pop().unwrap_or(self.proxies.len());
+        let (min, max) = self.interval(&rect);
+        self.endpoints.push(Endpoint {
+            value: min,
+            proxy: idx,
+            is_min: true,
+        });
+        self.endpoints.push(Endpoint {
+            value: max,
+            proxy: idx,
+            is_min: false,
+        });
+        let proxy = Proxy {
+            rect,
+            data,
+            min_endpoint: self.endpoints.len() - 2,
+            max_endpoint: self.endpoints.len() - 1,
+            partners: HashSet::new(),
+            dirty: true,
+        };
+        if idx == self.proxies.len() {
+            self.proxies.push(Some(proxy));
+        } else {
+            self.proxies[idx] = Some(proxy);
+        }
+        ProxyId(idx)
+    }
+    /// Removes a proxy, `End` events for the pairs it was part of are reported
+    /// by the next `update`.
+    pub fn remove(&mut self, id: ProxyId) -> Option<T> {
+        let proxy = self.proxies.get_mut(id.0)?.take()?;
+        for partner in proxy.partners {
+            self.unlink(id.0, partner);
+        }
+        self.endpoints.retain(|endpoint| endpoint.proxy != id.0);
+        for (idx, endpoint) in self.endpoints.iter().enumerate() {
+            if let Some(proxy) = &mut self.proxies[endpoint.proxy] {
+                if endpoint.is_min {
+                    proxy.min_endpoint = idx;
+                } else {
+                    proxy.max_endpoint = idx;
+                }
+            }
+        }
+        self.free.push(id.0);
+        Some(proxy.data)
+    }
+    pub fn move_proxy(&mut self, id: ProxyId, rect: Rect2<D, U>) {
+        let (min, max) = self.interval(&rect);
+        let Some(Some(proxy)) = self.proxies.get_mut(id.0) else {
+            return;
+        };
+        proxy.rect = rect;
+        proxy.dirty = true;
+        self.endpoints[proxy.min_endpoint].value = min;
+        self.endpoints[proxy.max_endpoint].value = max;
+    }
+
+    pub fn get(&self, id: ProxyId) -> Option<&T> {
+        self.proxy(id).map(|proxy| &proxy.data)
+    }
+    pub fn get_mut(&mut self, id: ProxyId) -> Option<&mut T> {
+        match self.proxies.get_mut(id.0) {
+            Some(Some(proxy)) => Some(&mut proxy.data),
+            _ => None,
+        }
+    }
+    pub fn rect(&self, id: ProxyId) -> Option<Rect2<D, U>> {
+        self.proxy(id).map(|proxy| proxy.rect)
+    }
+    pub fn iter(&self) -> impl Iterator<Item = (ProxyId, &T)> {
+        self.proxies
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, proxy)| proxy.as_ref().map(|proxy| (ProxyId(idx), &proxy.data)))
+    }
+    /// Pairs that were overlapping as of the last `update`.
+    pub fn overlapping_pairs(&self) -> impl Iterator<Item = (ProxyId, ProxyId)> + '_ {
+        self.overlapping
+            .iter()
+            .map(|&(a, b)| (ProxyId(a), ProxyId(b)))
+    }
+
+    pub fn update(&mut self) -> Vec<PairEvent> {
+        // The endpoints are mostly sorted between updates, so insertion sort
+        // stays close to linear.
+        for i in 1..self.endpoints.len() {
+            let mut j = i;
+            while j > 0 && self.endpoints[j - 1].sorts_after(&self.endpoints[j]) {
+                let left = self.endpoints[j - 1];
+                let right = self.endpoints[j];
+                if right.is_min && !left.is_min {
+                    self.link(right.proxy, left.proxy);
+                } else if !right.is_min && left.is_min {
+                    self.unlink(right.proxy, left.proxy);
+                }
+                self.endpoints.swap(j - 1, j);
+                self.set_endpoint(j - 1);
+                self.set_endpoint(j);
+                j -= 1;
+            }
+        }
+
+        for idx in 0..self.proxies.len() {
+            let Some(proxy) = &mut self.proxies[idx] else {
+                continue;
+            };
+            if !std::mem::take(&mut proxy.dirty) {
+                continue;
+            }
+            let rect = proxy.rect;
+            let partners: Vec<usize> = proxy.partners.iter().copied().collect();
+            for partner in partners {
+                let Some(other) = &self.proxies[partner] else {
+                    continue;
+                };
+                let key = pair_key(idx, partner);
+                let overlaps = rect.intersects(&other.rect);
+                if overlaps && self.overlapping.insert(key) {
+                    self.events
+                        .push(PairEvent::Begin(ProxyId(key.0), ProxyId(key.1)));
+                } else if !overlaps && self.overlapping.remove(&key) {
+                    self.events
+                        .push(PairEvent::End(ProxyId(key.0), ProxyId(key.1)));
+                }
+            }
+        }
+        std::mem::take(&mut self.events)
+    }
+
+    fn proxy(&self, id: ProxyId) -> Option<&Proxy<D, T, U>> {
+        self.proxies.get(id.0)?.as_ref()
+    }
+    fn interval(&self, rect: &Rect2<D, U>) -> (D, D) {
+        match self.axis {
+            Axis::X => (rect.min().x, rect.max().x),
+            Axis::Y => (rect.min().y, rect.max().y),
+        }
+    }
+    fn set_endpoint(&mut self, idx: usize) {
+        let endpoint = self.endpoints[idx];
+        if let Some(proxy) = &mut self.proxies[endpoint.proxy] {
+            if endpoint.is_min {
+                proxy.min_endpoint = idx;
+            } else {
+                proxy.max_endpoint = idx;
+            }
+        }
+    }
+    fn link(&mut self, a: usize, b: usize) {
+        if let Some(proxy) = &mut self.proxies[a] {
+            proxy.partners.insert(b);
+        }
+        if let Some(proxy) = &mut self.proxies[b] {
+            proxy.partners.insert(a);
+        }
+    }
+    fn unlink(&mut self, a: usize, b: usize) {
+        if let Some(proxy) = &mut self.proxies[a] {
+            proxy.partners.remove(&b);
+        }
+        if let Some(proxy) = &mut self.proxies[b] {
+            proxy.partners.remove(&a);
+        }
+        let key = pair_key(a, b);
+        if self.overlapping.remove(&key) {
+            self.events
+                .push(PairEvent::End(ProxyId(key.0), ProxyId(key.1)));
+        }
+    }
+}
+
+fn pair_key(a: usize, b: usize) -> (usize, usize) {
+    if a < b { (a, b) } else { (b, a) }
+}
@@ -0,0 +1,195 @@
+use crate::math::{Decimal, Rect2, ScreenSpace, Vector2};
+
+/// Normalized area of the parent a child is attached to, `(0, 0)` is the
+/// top-left corner of the parent and `(1, 1)` the bottom-right one.
+///
+/// When `min` and `max` are equal along an axis the child keeps its size along
+/// it, otherwise it stretches with the parent.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Anchor<D>
+where
+    D: Decimal,
+{
+    pub min: Vector2<D>,
+    pub max: Vector2<D>,
+}
+impl<D> Anchor<D>
+where
+    D: Decimal,
+{
+    pub fn new(min: Vector2<D>, max: Vector2<D>) -> Self {
+        Self { min, max }
+    }
+    pub fn point(x: D, y: D) -> Self {
+        Self::new(Vector2::new(x, y), Vector2::new(x, y))
+    }
+    pub fn stretch() -> Self {
+        Self::new(
+            Vector2::new(D::zero(), D::zero()),
+            Vector2::new(D::one(), D::one()),
+        )
+    }
+    /// Stretches along x, attached at `y` vertically.
+    pub fn stretch_horizontal(y: D) -> Self {
+        Self::new(Vector2::new(D::zero(), y), Vector2::new(D::one(), y))
+    }
+    /// Stretches along y, attached at `x` horizontally.
+    pub fn stretch_vertical(x: D) -> Self {
+        Self::new(Vector2::new(x, D::zero()), Vector2::new(x, D::one()))
+    }
+
+    pub fn top_left() -> Self {
+        Self::point(D::zero(), D::zero())
+    }
+    pub fn top() -> Self {
+        Self::point(half(), D::zero())
+    }
+    pub fn top_right() -> Self {
+        Self::point(D::one(), D::zero())
+    }
+    pub fn left() -> Self {
+        Self::point(D::zero(), half())
+    }
+    pub fn center() -> Self {
+        Self::point(half(), half())
+    }
+    pub fn right() -> Self {
+        Self::point(D::one(), half())
+    }
+    pub fn bottom_left() -> Self {
+        Self::point(D::zero(), D::one())
+    }
+    pub fn bottom() -> Self {
+        Self::point(half(), D::one())
+    }
+    pub fn bottom_right() -> Self {
+        Self::point(D::one(), D::one())
+    }
+
+    /// Area covered by the anchors inside `parent`.
+    pub fn resolve(&self, parent: &Rect2<D, ScreenSpace>) -> Rect2<D, ScreenSpace> {
+        Rect2::new(
+            normalized_point(parent, self.min),
+            normalized_point(parent, self.max),
+        )
+    }
+}
+
+/// Normalized point of the child its position refers to, and around which it
+/// grows when resized.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Pivot<D>(pub Vector2<D>)
+where
+    D: Decimal;
+impl<D> Pivot<D>
+where
+    D: Decimal,
+{
+    pub fn new(x: D, y: D) -> Self {
+        Self(Vector2::new(x, y))
+    }
+    pub fn top_left() -> Self {
+        Self::new(D::zero(), D::zero())
+    }
+    pub fn center() -> Self {
+        Self::new(half(), half())
+    }
+}
+
+/// Child placement relative to its parent.
+///
+/// `position` is the offset from the anchor reference point (the anchors
+/// interpolated by the pivot) to the pivot of the child, and `size_delta` is
+/// how much bigger the child is than the area between its anchors.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct AnchoredRect<D>
+where
+    D: Decimal,
+{
+    pub anchor: Anchor<D>,
+    pub pivot: Pivot<D>,
+    pub position: Vector2<D, ScreenSpace>,
+    pub size_delta: Vector2<D, ScreenSpace>,
+}
+impl<D> AnchoredRect<D>
+where
+    D: Decimal,
+{
+    pub fn new(
+        anchor: Anchor<D>,
+        pivot: Pivot<D>,
+        position: Vector2<D, ScreenSpace>,
+        size_delta: Vector2<D, ScreenSpace>,
+    ) -> Self {
+        Self {
+            anchor,
+            pivot,
+            position,
+            size_delta,
+        }
+    }
+    /// Anchored placement that resolves to `rect` inside `parent`.
+    pub fn from_rect(
+        parent: &Rect2<D, ScreenSpace>,
+        rect: &Rect2<D, ScreenSpace>,
+        anchor: Anchor<D>,
+        pivot: Pivot<D>,
+    ) -> Self {
+        let area = anchor.resolve(parent);
+        let size = rect.size();
+        let pivot_point = rect.min() + scale(size, pivot.0);
+        Self {
+            anchor,
+            pivot,
+            position: pivot_point - reference_point(&area, pivot),
+            size_delta: size - area.size(),
+        }
+    }
+
+    pub fn size(&self, parent: &Rect2<D, ScreenSpace>) -> Vector2<D, ScreenSpace> {
+        let size = self.anchor.resolve(parent).size() + self.size_delta;
+        Vector2::new(size.x.max(D::zero()), size.y.max(D::zero()))
+    }
+    pub fn resolve(&self, parent: &Rect2<D, ScreenSpace>) -> Rect2<D, ScreenSpace> {
+        let area = self.anchor.resolve(parent);
+        let size = self.size(parent);
+        let pivot_point = reference_point(&area, self.pivot) + self.position;
+        Rect2::from_size(pivot_point - scale(size, self.pivot.0), size)
+    }
+    /// Changes the anchors and pivot while keeping the resolved rect inside
+    /// `parent` where it is.
+    pub fn reanchor(&mut self, parent: &Rect2<D, ScreenSpace>, anchor: Anchor<D>, pivot: Pivot<D>) {
+        *self = Self::from_rect(parent, &self.resolve(parent), anchor, pivot);
+    }
+}
+
+fn half<D>() -> D
+where
+    D: Decimal,
+{
+    D::one() / (D::one() + D::one())
+}
+fn scale<D>(size: Vector2<D, ScreenSpace>, normalized: Vector2<D>) -> Vector2<D, ScreenSpace>
+where
+    D: Decimal,
+{
+    Vector2::new(size.x * normalized.x, size.y * normalized.y)
+}
+fn normalized_point<D>(
+    rect: &Rect2<D, ScreenSpace>,
+    normalized: Vector2<D>,
+) -> Vector2<D, ScreenSpace>
+where
+    D: Decimal,
+{
+    rect.min() + scale(rect.size(), normalized)
+}
+fn reference_point<D>(area: &Rect2<D, ScreenSpace>, pivot: Pivot<D>) -> Vector2<D, ScreenSpace>
+where
+    D: Decimal,
+{
+    normalized_point(area, pivot.0)
+}
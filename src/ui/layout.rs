@@ -0,0 +1,361 @@
+use crate::math::{Decimal, EdgeInsets, Rect2, Unit, Vector2};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Direction {
+    /// Children are laid out left to right.
+    #[default]
+    Row,
+    /// Children are laid out top to bottom.
+    Column,
+}
+
+/// Distribution of the leftover space along the main axis.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Justify {
+    #[default]
+    Start,
+    End,
+    Center,
+    SpaceBetween,
+    SpaceAround,
+    SpaceEvenly,
+}
+
+/// Placement of a child along the cross axis.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Align {
+    Start,
+    End,
+    Center,
+    #[default]
+    Stretch,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct FlexItem<D, U = ()>
+where
+    D: Decimal,
+    U: Unit,
+{
+    pub preferred: Vector2<D, U>,
+    pub min: Vector2<D, U>,
+    pub max: Vector2<D, U>,
+    /// Share of the leftover space taken when the children don't fill the
+    /// parent.
+    pub grow: D,
+    /// Share of the missing space given up when the children overflow the
+    /// parent, weighted by the preferred size.
+    pub shrink: D,
+    /// Overrides the cross axis alignment of the layout for this child.
+    pub align_self: Option<Align>,
+}
+impl<D, U> FlexItem<D, U>
+where
+    D: Decimal,
+    U: Unit,
+{
+    pub fn new(preferred: Vector2<D, U>) -> Self {
+        Self {
+            preferred,
+            min: Vector2::new(D::zero(), D::zero()),
+            max: Vector2::new(D::max_value(), D::max_value()),
+            grow: D::zero(),
+            shrink: D::one(),
+            align_self: None,
+        }
+    }
+}
+
+/// Single line flexbox-like layout.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct FlexLayout<D>
+where
+    D: Decimal,
+{
+    pub direction: Direction,
+    pub justify: Justify,
+    pub align: Align,
+    /// Space between consecutive children.
+    pub gap: D,
+    pub padding: EdgeInsets<D>,
+}
+impl<D> Default for FlexLayout<D>
+where
+    D: Decimal,
+{
+    fn default() -> Self {
+        Self {
+            direction: Direction::default(),
+            justify: Justify::default(),
+            align: Align::default(),
+            gap: D::zero(),
+            padding: EdgeInsets::splat(D::zero()),
+        }
+    }
+}
+impl<D> FlexLayout<D>
+where
+    D: Decimal,
+{
+    /// Computes the rect of every child inside `parent`, in the same order as
+    /// `items`. Children that can't shrink enough overflow past the end.
+    pub fn solve<U>(&self, parent: Rect2<D, U>, items: &[FlexItem<D, U>]) -> Vec<Rect2<D, U>>
+    where
+        U: Unit,
+    {
+        if items.is_empty() {
+            return Vec::new();
+        }
+        let content = parent.inset(&self.padding);
+        let (main_start, cross_start) = self.split(content.min());
+        let (main_size, cross_size) = self.split(content.size());
+        let count = D::from_i32(items.len().min(i32::MAX as usize) as i32);
+        let gaps = self.gap * (count - D::one());
+        let available = main_size - gaps;
+
+        let sizes = self.resolve_main(items, available);
+        let used = sizes.iter().fold(D::zero(), |acc, size| acc + *size);
+        let free = (available - used).max(D::zero());
+
+        let two = D::one() + D::one();
+        let (mut cursor, spacing) = match self.justify {
+            Justify::Start => (D::zero(), self.gap),
+            Justify::End => (free, self.gap),
+            Justify::Center => (free / two, self.gap),
+            Justify::SpaceBetween if items.len() > 1 => {
+                (D::zero(), self.gap + free / (count - D::one()))
+            }
+            Justify::SpaceBetween => (D::zero(), self.gap),
+            Justify::SpaceAround => (free / count / two, self.gap + free / count),
+            Justify::SpaceEvenly => {
+                let space = free / (count + D::one());
+                (space, self.gap + space)
+            }
+        };
+
+        let mut rects = Vec::with_capacity(items.len());
+        for (item, main) in items.iter().zip(sizes) {
+            let (_, min) = self.split(item.min);
+            let (_, max) = self.split(item.max);
+            let (_, preferred) = self.split(item.preferred);
+            let align = item.align_self.unwrap_or(self.align);
+            let cross = match align {
+                Align::Stretch => cross_size,
+                _ => preferred,
+            }
+            .clamp(min, max);
+            let offset = match align {
+                Align::Start | Align::Stretch => D::zero(),
+                Align::End => cross_size - cross,
+                Align::Center => (cross_size - cross) / two,
+            };
+
+            let origin = self.join(main_start + cursor, cross_start + offset);
+            rects.push(Rect2::from_size(origin, self.join(main, cross)));
+            cursor += main + spacing;
+        }
+        rects
+    }
+
+    fn resolve_main<U>(&self, items: &[FlexItem<D, U>], available: D) -> Vec<D>
+    where
+        U: Unit,
+    {
+        let limits: Vec<(D, D, D)> = items
+            .iter()
+            .map(|item| {
+                let (min, _) = self.split(item.min);
+                let (max, _) = self.split(item.max);
+                let (preferred, _) = self.split(item.preferred);
+                (preferred.clamp(min, max), min, max)
+            })
+            .collect();
+        let mut sizes: Vec<D> = limits.iter().map(|(base, _, _)| *base).collect();
+        let mut frozen = vec![false; items.len()];
+
+        // Every pass either settles the remaining space or freezes at least
+        // one child at its limit.
+        loop {
+            let used = sizes.iter().fold(D::zero(), |acc, size| acc + *size);
+            let free = available - used;
+            if free == D::zero() {
+                break;
+            }
+            let growing = free > D::zero();
+            let weight = |idx: usize| {
+                if growing {
+                    items[idx].grow
+                } else {
+                    items[idx].shrink * limits[idx].0
+                }
+            };
+            let total = (0..items.len())
+                .filter(|&idx| !frozen[idx])
+                .fold(D::zero(), |acc, idx| acc + weight(idx).max(D::zero()));
+            if total <= D::zero() {
+                break;
+            }
+
+            let mut clamped = false;
+            for idx in 0..items.len() {
+                if frozen[idx] {
+                    continue;
+                }
+                let (_, min, max) = limits[idx];
+                let target = sizes[idx] + free * weight(idx).max(D::zero()) / total;
+                let size = target.clamp(min, max);
+                if size != target {
+                    frozen[idx] = true;
+                    clamped = true;
+                }
+                sizes[idx] = size;
+            }
+            if !clamped {
+                break;
+            }
+        }
+        sizes
+    }
+
+    fn split<U>(&self, vector: Vector2<D, U>) -> (D, D)
+    where
+        U: Unit,
+    {
+        match self.direction {
+            Direction::Row => (vector.x, vector.y),
+            Direction::Column => (vector.y, vector.x),
+        }
+    }
+    fn join<U>(&self, main: D, cross: D) -> Vector2<D, U>
+    where
+        U: Unit,
+    {
+        match self.direction {
+            Direction::Row => Vector2::new(main, cross),
+            Direction::Column => Vector2::new(cross, main),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parent(width: f32, height: f32) -> Rect2<f32> {
+        Rect2::from_size(Vector2::new(0.0, 0.0), Vector2::new(width, height))
+    }
+    fn item(width: f32, height: f32) -> FlexItem<f32> {
+        FlexItem::new(Vector2::new(width, height))
+    }
+    fn mains(rects: &[Rect2<f32>]) -> Vec<(f32, f32)> {
+        rects
+            .iter()
+            .map(|rect| (rect.min().x, rect.size().x))
+            .collect()
+    }
+
+    #[test]
+    fn grow_redistributes_past_max() {
+        let layout = FlexLayout {
+            gap: 10.0,
+            ..Default::default()
+        };
+        let mut a = item(0.0, 10.0);
+        a.grow = 1.0;
+        let mut b = item(0.0, 10.0);
+        b.grow = 3.0;
+        b.max.x = 150.0;
+        let rects = layout.solve(parent(400.0, 10.0), &[a, b]);
+        assert_eq!(mains(&rects), vec![(0.0, 240.0), (250.0, 150.0)]);
+    }
+
+    #[test]
+    fn shrink_is_weighted_by_preferred_size() {
+        let layout = FlexLayout::default();
+        let rects = layout.solve(parent(150.0, 10.0), &[item(150.0, 10.0), item(50.0, 10.0)]);
+        assert_eq!(mains(&rects), vec![(0.0, 112.5), (112.5, 37.5)]);
+    }
+
+    #[test]
+    fn overflows_past_min() {
+        let layout = FlexLayout::default();
+        let mut a = item(100.0, 10.0);
+        a.min.x = 80.0;
+        let rects = layout.solve(parent(100.0, 10.0), &[a, a]);
+        assert_eq!(mains(&rects), vec![(0.0, 80.0), (80.0, 80.0)]);
+    }
+
+    #[test]
+    fn justify() {
+        let items = [item(20.0, 10.0), item(20.0, 10.0)];
+        let cases = [
+            (Justify::Start, [0.0, 20.0]),
+            (Justify::End, [60.0, 80.0]),
+            (Justify::Center, [30.0, 50.0]),
+            (Justify::SpaceBetween, [0.0, 80.0]),
+            (Justify::SpaceAround, [15.0, 65.0]),
+            (Justify::SpaceEvenly, [20.0, 60.0]),
+        ];
+        for (justify, starts) in cases {
+            let layout = FlexLayout {
+                justify,
+                ..Default::default()
+            };
+            let rects = layout.solve(parent(100.0, 10.0), &items);
+            let found: Vec<f32> = rects.iter().map(|rect| rect.min().x).collect();
+            assert_eq!(found, starts, "{justify:?}");
+        }
+
+        let layout = FlexLayout {
+            justify: Justify::SpaceBetween,
+            ..Default::default()
+        };
+        let rects = layout.solve(parent(100.0, 10.0), &items[..1]);
+        assert_eq!(mains(&rects), vec![(0.0, 20.0)]);
+    }
+
+    #[test]
+    fn align_self_overrides_align() {
+        let layout = FlexLayout::default();
+        let mut end = item(20.0, 10.0);
+        end.align_self = Some(Align::End);
+        let rects = layout.solve(parent(100.0, 50.0), &[item(20.0, 10.0), end]);
+        assert_eq!(rects[0].min().y, 0.0);
+        assert_eq!(rects[0].size().y, 50.0);
+        assert_eq!(rects[1].min().y, 40.0);
+        assert_eq!(rects[1].size().y, 10.0);
+    }
+
+    #[test]
+    fn column() {
+        let layout = FlexLayout {
+            direction: Direction::Column,
+            ..Default::default()
+        };
+        let mut a = item(10.0, 50.0);
+        a.grow = 1.0;
+        let rects = layout.solve(parent(100.0, 200.0), &[a, a]);
+        assert_eq!(rects[0].min(), Vector2::new(0.0, 0.0));
+        assert_eq!(rects[0].size(), Vector2::new(100.0, 100.0));
+        assert_eq!(rects[1].min(), Vector2::new(0.0, 100.0));
+        assert_eq!(rects[1].size(), Vector2::new(100.0, 100.0));
+    }
+
+    #[test]
+    fn padding_insets_the_parent() {
+        let layout = FlexLayout {
+            padding: EdgeInsets::new(5.0, 10.0, 15.0, 20.0),
+            ..Default::default()
+        };
+        let mut a = item(0.0, 0.0);
+        a.grow = 1.0;
+        let rects = layout.solve(parent(100.0, 100.0), &[a]);
+        assert_eq!(rects[0].min(), Vector2::new(20.0, 5.0));
+        assert_eq!(rects[0].size(), Vector2::new(70.0, 80.0));
+    }
+}
@@ -0,0 +1,9 @@
+pub mod anchor;
+pub mod layout;
+pub mod nine_slice;
+
+pub use crate::ui::{
+    anchor::{Anchor, AnchoredRect, Pivot},
+    layout::{Align, Direction, FlexItem, FlexLayout, Justify},
+    nine_slice::{SliceMode, SlicePatch, nine_slice},
+};
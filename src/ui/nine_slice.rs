@@ -0,0 +1,196 @@
+use crate::math::{Decimal, EdgeInsets, Rect2, TexelSpace, Unit, Vector2};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum SliceMode {
+    /// Edges and center are stretched to fill the destination.
+    #[default]
+    Stretch,
+    /// Edges and center are repeated at one texel per unit along the axes
+    /// they would otherwise be stretched on, the last tile is cropped.
+    Tile,
+}
+
+/// Part of a texture and where it should be drawn.
+#[derive(Debug, Clone, Copy)]
+pub struct SlicePatch<D, U = ()>
+where
+    D: Decimal,
+    U: Unit,
+{
+    pub source: Rect2<u32, TexelSpace>,
+    pub destination: Rect2<D, U>,
+}
+
+/// Maps `source` onto `destination` keeping the borders described by `insets`
+/// unscaled.
+///
+/// With `SliceMode::Stretch` the nine patches are returned row by row starting
+/// at the top-left corner. If the destination is smaller than the insets, the
+/// corners are scaled down along that axis so they still fit.
+pub fn nine_slice<D, U>(
+    source: Rect2<u32, TexelSpace>,
+    destination: Rect2<D, U>,
+    insets: &EdgeInsets<u32>,
+    mode: SliceMode,
+) -> Vec<SlicePatch<D, U>>
+where
+    D: Decimal,
+    U: Unit,
+{
+    let (src_min, src_max) = (source.min(), source.max());
+    let left = insets.left.min(source.width());
+    let right = insets.right.min(source.width() - left);
+    let top = insets.top.min(source.height());
+    let bottom = insets.bottom.min(source.height() - top);
+    let src_x = [src_min.x, src_min.x + left, src_max.x - right, src_max.x];
+    let src_y = [src_min.y, src_min.y + top, src_max.y - bottom, src_max.y];
+
+    let (dst_min, dst_max) = (destination.min(), destination.max());
+    let size = destination.size();
+    let scale = |near: u32, far: u32, available: D| {
+        let total = to_decimal::<D>(near + far);
+        if total > available && total > D::zero() {
+            available / total
+        } else {
+            D::one()
+        }
+    };
+    let scale_x = scale(left, right, size.x);
+    let scale_y = scale(top, bottom, size.y);
+    let dst_x = [
+        dst_min.x,
+        dst_min.x + to_decimal::<D>(left) * scale_x,
+        dst_max.x - to_decimal::<D>(right) * scale_x,
+        dst_max.x,
+    ];
+    let dst_y = [
+        dst_min.y,
+        dst_min.y + to_decimal::<D>(top) * scale_y,
+        dst_max.y - to_decimal::<D>(bottom) * scale_y,
+        dst_max.y,
+    ];
+
+    let mut patches = Vec::with_capacity(9);
+    for row in 0..3 {
+        for column in 0..3 {
+            let patch = SlicePatch {
+                source: Rect2::new(
+                    Vector2::new(src_x[column], src_y[row]),
+                    Vector2::new(src_x[column + 1], src_y[row + 1]),
+                ),
+                destination: Rect2::new(
+                    Vector2::new(dst_x[column], dst_y[row]),
+                    Vector2::new(dst_x[column + 1], dst_y[row + 1]),
+                ),
+            };
+            match mode {
+                SliceMode::Stretch => patches.push(patch),
+                SliceMode::Tile => tile(patch, column == 1, row == 1, &mut patches),
+            }
+        }
+    }
+    patches
+}
+
+fn tile<D, U>(
+    patch: SlicePatch<D, U>,
+    along_x: bool,
+    along_y: bool,
+    patches: &mut Vec<SlicePatch<D, U>>,
+) where
+    D: Decimal,
+    U: Unit,
+{
+    let columns = tile_spans(
+        patch.source.min().x,
+        patch.source.max().x,
+        patch.destination.min().x,
+        patch.destination.max().x,
+        along_x,
+    );
+    let rows = tile_spans(
+        patch.source.min().y,
+        patch.source.max().y,
+        patch.destination.min().y,
+        patch.destination.max().y,
+        along_y,
+    );
+    for y in &rows {
+        for x in &columns {
+            patches.push(SlicePatch {
+                source: Rect2::new(
+                    Vector2::new(x.source.0, y.source.0),
+                    Vector2::new(x.source.1, y.source.1),
+                ),
+                destination: Rect2::new(
+                    Vector2::new(x.destination.0, y.destination.0),
+                    Vector2::new(x.destination.1, y.destination.1),
+                ),
+            });
+        }
+    }
+}
+
+struct Span<D> {
+    source: (u32, u32),
+    destination: (D, D),
+}
+/// Splits a span along one axis into source sized repetitions.
+fn tile_spans<D>(src_min: u32, src_max: u32, dst_min: D, dst_max: D, repeat: bool) -> Vec<Span<D>>
+where
+    D: Decimal,
+{
+    let length = src_max - src_min;
+    if !repeat || length == 0 {
+        return vec![Span {
+            source: (src_min, src_max),
+            destination: (dst_min, dst_max),
+        }];
+    }
+    let step = to_decimal::<D>(length);
+    // Every start is derived from the index rather than accumulated, far from
+    // the origin `start + step` may round back to `start`.
+    let count = ((dst_max - dst_min) / step).ceil().to_i32().max(0);
+    let mut spans = Vec::with_capacity(count as usize);
+    for idx in 0..count {
+        let start = dst_min + D::from_i32(idx) * step;
+        let end = (dst_min + D::from_i32(idx + 1) * step).min(dst_max);
+        if end <= start {
+            continue;
+        }
+        let cropped = ((end - start).ceil().to_i32().max(1) as u32).min(length);
+        spans.push(Span {
+            source: (src_min, src_min + cropped),
+            destination: (start, end),
+        });
+    }
+    spans
+}
+
+fn to_decimal<D>(value: u32) -> D
+where
+    D: Decimal,
+{
+    D::from_i32(value.min(i32::MAX as u32) as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tile_far_from_origin() {
+        let source = Rect2::new(Vector2::new(0, 0), Vector2::new(3, 3));
+        let destination =
+            Rect2::<f32>::new(Vector2::new(2.0e7, 0.0), Vector2::new(2.0e7 + 64.0, 10.0));
+        let patches = nine_slice(source, destination, &EdgeInsets::splat(1), SliceMode::Tile);
+        // At most one tile per unit on each axis, next to the corners.
+        assert!(!patches.is_empty() && patches.len() <= 66 * 10);
+        for patch in &patches {
+            let (min, max) = (patch.destination.min(), patch.destination.max());
+            assert!(min.x <= max.x && min.y <= max.y);
+            assert!(min.x >= destination.min().x && max.x <= destination.max().x);
+        }
+    }
+}